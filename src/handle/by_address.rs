@@ -0,0 +1,85 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::Handle;
+
+/// A wrapper that compares and hashes a [`Handle`] by the identity of the referenced value,
+/// instead of by the value itself.
+///
+/// Two [`ByAddress`] wrappers are equal only if their [`Handle`]s are [`Handle::ref_eq`]. Hashing
+/// only hashes the address of the referenced value, so it is `O(1)` regardless of the size of
+/// `T`, and `T` does not need to implement [`Hash`] or [`Eq`].
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::HashSet;
+/// use share_map::{ByAddress, ShareMap};
+///
+/// let map1 = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+/// let map2 = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+///
+/// let mut touched = HashSet::new();
+/// touched.insert(ByAddress(map1.get_handle("key1").ok_or("Key not found")?));
+/// touched.insert(ByAddress(map1.get_handle("key1").ok_or("Key not found")?));
+/// touched.insert(ByAddress(map2.get_handle("key1").ok_or("Key not found")?));
+///
+/// // equal values from different maps are distinct entries
+/// assert_eq!(touched.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ByAddress<H>(pub H);
+
+impl<T> ByAddress<Handle<T>> {
+    /// Consumes the wrapper, returning the wrapped [`Handle`].
+    #[must_use]
+    pub fn into_inner(self) -> Handle<T> {
+        self.0
+    }
+}
+
+impl<T> From<Handle<T>> for ByAddress<Handle<T>> {
+    fn from(handle: Handle<T>) -> Self {
+        Self(handle)
+    }
+}
+
+impl<T> Deref for ByAddress<Handle<T>> {
+    type Target = Handle<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<Handle<T>> for ByAddress<Handle<T>> {
+    fn as_ref(&self) -> &Handle<T> {
+        &self.0
+    }
+}
+
+/// If `T` implements [Debug], [`ByAddress`] implements [Debug] by delegating to the [`Handle`].
+impl<T: Debug> Debug for ByAddress<Handle<T>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_tuple("ByAddress").field(&self.0).finish()
+    }
+}
+
+impl<T> Hash for ByAddress<Handle<T>> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(&raw const *self.0, state);
+    }
+}
+
+/// [`ByAddress`] equality is reference equality, see [`Handle::ref_eq`].
+impl<T> PartialEq for ByAddress<Handle<T>> {
+    fn eq(&self, other: &Self) -> bool {
+        Handle::ref_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Eq for ByAddress<Handle<T>> {}
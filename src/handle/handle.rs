@@ -3,21 +3,55 @@ use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
+use std::ptr::NonNull;
 use std::sync::Arc;
 
+use crate::handle::{Store, try_unwrap_store};
+
 /// An immutable reference to a shared value.
 ///
 /// Implements most common traits via deref to the referenced value and can be cheaply cloned.
-#[derive(Clone)]
+///
+/// A [`Handle`] is two words: a thin pointer to the reference counted value store that keeps
+/// the value alive, and a pointer directly to the referenced value, so dereferencing needs no
+/// bounds check or index arithmetic.
 pub struct Handle<T> {
-    store: Arc<[T]>,
-    index: usize,
+    store: Store<T>,
+    value: NonNull<T>,
+}
+
+// SAFETY: `value` is only ever used as a shared reference into `store`, so a `Handle` is
+// equivalent to an `Arc<[T]>` plus a `&T` borrowed from it. Sending or sharing it across threads
+// shares and eventually drops the values, which requires `T: Send + Sync`, as for `Arc<[T]>`.
+unsafe impl<T: Send + Sync> Send for Handle<T> {}
+// SAFETY: see `Send` above
+unsafe impl<T: Send + Sync> Sync for Handle<T> {}
+
+/// The distance in bytes between the values of a store as addressed by [`Handle`]s.
+///
+/// Zero sized values all share one address, so their [`Handle`]s are spread out by alignment
+/// instead, which keeps [`Handle::ref_eq`] and [`Handle::index`] working. Any non-null, aligned
+/// pointer is valid for a zero sized read.
+const fn stride<T>() -> usize {
+    match size_of::<T>() {
+        0 => align_of::<T>(),
+        size => size,
+    }
 }
 
 impl<T> Handle<T> {
-    pub(crate) fn new(store: Arc<[T]>, index: usize) -> Self {
-        debug_assert!(index < store.len());
-        Self { store, index }
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of `store`.
+    pub(crate) fn new(store: Store<T>, index: usize) -> Self {
+        assert!(index < store.len(), "index out of bounds");
+
+        // PANIC SAFETY: the store's pointer is non null, and `index` is in bounds, so the offset
+        // stays within the store (or aligned past it, for zero sized values)
+        let value = store.as_ptr().wrapping_byte_add(index * stride::<T>());
+        let value = NonNull::new(value.cast_mut()).expect("value pointer should be non null");
+
+        Self { store, value }
     }
 
     /// Returns `true` if the two referenced values are equal.
@@ -109,7 +143,7 @@ impl<T> Handle<T> {
     #[must_use]
     #[inline]
     pub fn ref_eq(this: &Self, other: &Self) -> bool {
-        std::ptr::eq(&raw const **this, &raw const **other)
+        this.value == other.value && Handle::same_store(this, other)
     }
 
     /// Returns `true` if the two [`Handle`]s reference different value instances.
@@ -118,7 +152,140 @@ impl<T> Handle<T> {
     #[must_use]
     #[inline]
     pub fn ref_ne(this: &Handle<T>, other: &Handle<T>) -> bool {
-        !std::ptr::eq(&raw const **this, &raw const **other)
+        !Handle::ref_eq(this, other)
+    }
+
+    /// Returns `true` if the two [`Handle`]s point into the same value store, that is, if they
+    /// were obtained from the same [`ShareMap`](crate::ShareMap) instance or its clones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, Handle};
+    ///
+    /// let pairs = [("key1", 42), ("key2", 100)];
+    /// let map1 = ShareMap::<_, _>::try_from_iter(pairs)?;
+    /// let map2 = ShareMap::<_, _>::try_from_iter(pairs)?;
+    ///
+    /// // same Map, different keys
+    /// let handle1 = map1.get_handle("key1").ok_or("Key not found")?;
+    /// let handle2 = map1.get_handle("key2").ok_or("Key not found")?;
+    /// assert!(Handle::same_store(&handle1, &handle2));
+    ///
+    /// // different Map, same key
+    /// let handle3 = map2.get_handle("key1").ok_or("Key not found")?;
+    /// assert!(!Handle::same_store(&handle1, &handle3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn same_store(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(Handle::store(this), Handle::store(other))
+    }
+
+    /// Returns the value store the [`Handle`] points into.
+    ///
+    /// The store contains every value of the originating [`ShareMap`](crate::ShareMap) snapshot,
+    /// in the order they were provided on construction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, Handle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)])?;
+    /// let handle = map.get_handle("key1").ok_or("Key not found")?;
+    ///
+    /// assert_eq!(&**Handle::store(&handle), [42, 100]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn store(this: &Self) -> &Arc<[T]> {
+        this.store.values()
+    }
+
+    /// Returns the index of the referenced value within its value store.
+    pub(crate) fn index(this: &Self) -> usize {
+        (this.value.addr().get() - this.store.as_ptr().addr()) / stride::<T>()
+    }
+
+    /// Attempts to take ownership of the referenced value.
+    ///
+    /// This succeeds only if `this` is the last reference to the value store it points into, that
+    /// is, the originating [`ShareMap`](crate::ShareMap) and all other [`Handle`]s into it have
+    /// been dropped. The other values in the store are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `this` unchanged if the value store is still shared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, Handle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", String::from("value"))])?;
+    /// let handle = map.get_handle("key1").ok_or("Key not found")?;
+    ///
+    /// // the map still shares the store
+    /// let handle = Handle::try_unwrap(handle).expect_err("store is shared");
+    ///
+    /// drop(map);
+    /// let value: String = Handle::try_unwrap(handle).map_err(|_| "store is shared")?;
+    /// assert_eq!(value, "value");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_unwrap(this: Self) -> Result<T, Self> {
+        let index = Handle::index(&this);
+        let Self { store, value } = this;
+        match try_unwrap_store(store) {
+            Ok(mut values) => Ok(values.swap_remove(index)),
+            Err(store) => Err(Self { store, value }),
+        }
+    }
+
+    /// Returns the referenced value, moving it out if `this` is the last reference to the value
+    /// store, and cloning it otherwise.
+    ///
+    /// See [`Handle::try_unwrap`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, Handle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", String::from("value"))])?;
+    /// let handle = map.get_handle("key1").ok_or("Key not found")?;
+    ///
+    /// // the map still shares the store, so the value is cloned
+    /// let value: String = Handle::into_owned(handle);
+    /// assert_eq!(value, "value");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_owned(this: Self) -> T
+    where
+        T: Clone,
+    {
+        Handle::try_unwrap(this).unwrap_or_else(|this| T::clone(&this))
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            value: self.value,
+        }
     }
 }
 
@@ -145,8 +312,10 @@ impl<T> Deref for Handle<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        // Panic safety: `index` is guaranteed to be in bounds
-        &self.store[self.index]
+        // SAFETY: `value` points to an in bounds value of `store` (or is an aligned pointer for a
+        // zero sized value), which is kept alive and never mutated for as long as this handle
+        // exists.
+        unsafe { self.value.as_ref() }
     }
 }
 
@@ -227,3 +396,24 @@ impl<T: serde::Serialize> serde::Serialize for Handle<T> {
         (**self).serialize(serializer)
     }
 }
+
+/// If `T` implements [`schemars::JsonSchema`], [`Handle`] has the same schema as `T`, matching its
+/// [`serde::Serialize`] implementation.
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for Handle<T> {
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        T::json_schema(generator)
+    }
+}
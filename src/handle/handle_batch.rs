@@ -0,0 +1,112 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::Handle;
+use crate::handle::Store;
+
+/// The results of a batched lookup, sharing a single reference to the value store.
+///
+/// Like [`Handle`], a [`HandleBatch`] keeps the value store it points into alive, but it does so
+/// with one reference for the whole batch, so creating it costs a single atomic increment
+/// regardless of how many keys were found. Individual [`Handle`]s can still be taken out of it,
+/// at the usual cost of one increment each.
+///
+/// Created by [`ShareMap::get_many_handles`](crate::ShareMap::get_many_handles).
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::ShareMap;
+///
+/// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+///
+/// let batch = map.get_many_handles(["key1", "key3", "key2"]);
+/// drop(map);
+///
+/// assert_eq!(batch.values(), [Some(&1), None, Some(&2)]);
+/// assert_eq!(batch.get(2), Some(&2));
+/// # Ok(())
+/// # }
+/// ```
+pub struct HandleBatch<T, const N: usize> {
+    store: Store<T>,
+    indices: [Option<usize>; N],
+}
+
+impl<T, const N: usize> HandleBatch<T, N> {
+    pub(crate) fn new(store: Store<T>, indices: [Option<usize>; N]) -> Self {
+        debug_assert!(indices.iter().flatten().all(|index| *index < store.len()));
+        Self { store, indices }
+    }
+
+    /// Returns the value found for the key at `position` in the lookup, if it was found.
+    ///
+    /// Returns [`None`] if the key was not found, or if `position` is out of bounds.
+    #[must_use]
+    pub fn get(&self, position: usize) -> Option<&T> {
+        self.indices
+            .get(position)
+            .copied()
+            .flatten()
+            .map(|index| &self.store[index])
+    }
+
+    /// Returns a [`Handle`] to the value found for the key at `position` in the lookup, if it was
+    /// found.
+    ///
+    /// Returns [`None`] if the key was not found, or if `position` is out of bounds.
+    #[must_use]
+    pub fn handle(&self, position: usize) -> Option<Handle<T>> {
+        self.indices
+            .get(position)
+            .copied()
+            .flatten()
+            .map(|index| Handle::new(self.store.clone(), index))
+    }
+
+    /// Returns the values found for each key, in the order of the lookup.
+    #[must_use]
+    pub fn values(&self) -> [Option<&T>; N] {
+        self.indices
+            .map(|index| index.map(|index| &self.store[index]))
+    }
+
+    /// Converts the batch into a [`Handle`] for each key that was found, in the order of the
+    /// lookup.
+    ///
+    /// Each [`Handle`] holds its own reference to the value store, so this costs one atomic
+    /// increment per found key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    ///
+    /// let [one, missing] = map.get_many_handles(["key1", "key3"]).into_handles();
+    /// assert_eq!(one.as_deref(), Some(&1));
+    /// assert!(missing.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_handles(self) -> [Option<Handle<T>>; N] {
+        self.indices
+            .map(|index| index.map(|index| Handle::new(self.store.clone(), index)))
+    }
+}
+
+// manual implementation is necessary because #Derive would require T: Clone
+impl<T, const N: usize> Clone for HandleBatch<T, N> {
+    fn clone(&self) -> Self {
+        Self::new(self.store.clone(), self.indices)
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for HandleBatch<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.values()).finish()
+    }
+}
@@ -0,0 +1,158 @@
+//! Serializes a [`Handle`] as the key of its value in a [`ShareMap`], and resolves the key
+//! back into a [`Handle`] on deserialization.
+//!
+//! Because a [`Handle`] does not know its key, and a key alone cannot be resolved into a
+//! [`Handle`], both directions borrow the [`ShareMap`] the [`Handle`]s belong to. Wrap a
+//! [`Handle`] in a [`HandleRef`] to serialize it as its key, and deserialize a key with a
+//! [`HandleSeed`] to resolve it. All [`Handle`]s resolved by a [`HandleSeed`] share the map's
+//! value store.
+//!
+//! Keys are resolved as strings, so only maps that can be queried by `str`, such as maps with
+//! `String` or `&str` keys, can resolve keys on deserialization.
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use serde::de::DeserializeSeed;
+//! use share_map::{Handle, ShareMap};
+//! use share_map::handle_ref::{HandleRef, HandleSeed};
+//!
+//! let patterns = ShareMap::<_, _>::try_from_iter([("digits", r"\d+".to_string())])?;
+//! let pattern = patterns.get_handle("digits").ok_or("Key not found")?;
+//!
+//! // the handle is serialized as its key
+//! let json = serde_json::to_string(&HandleRef::new(&patterns, &pattern))?;
+//! assert_eq!(json, r#""digits""#);
+//!
+//! // and resolved against the map on deserialization
+//! let mut deserializer = serde_json::Deserializer::from_str(&json);
+//! let resolved = HandleSeed::new(&patterns).deserialize(&mut deserializer)?;
+//! assert!(Handle::ref_eq(&resolved, &pattern));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Debug, Formatter};
+
+use frozen_collections::{MapIteration, MapQuery};
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+
+use crate::{Handle, ShareMap};
+
+/// Serializes a [`Handle`] as the key of its value in a borrowed [`ShareMap`].
+///
+/// The key is found with [`ShareMap::key_of`], so serializing is `O(n)` in the number of
+/// entries of the map.
+///
+/// See the [module](self) documentation for an example.
+pub struct HandleRef<'a, K, V, Map> {
+    map: &'a ShareMap<K, V, Map>,
+    handle: &'a Handle<V>,
+}
+
+impl<'a, K, V, Map> HandleRef<'a, K, V, Map> {
+    /// Creates a new [`HandleRef`] serializing `handle` as its key in `map`.
+    #[must_use]
+    pub fn new(map: &'a ShareMap<K, V, Map>, handle: &'a Handle<V>) -> Self {
+        Self { map, handle }
+    }
+}
+
+impl<K, V, Map> Debug for HandleRef<'_, K, V, Map> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandleRef").finish_non_exhaustive()
+    }
+}
+
+impl<K, V, Map> Serialize for HandleRef<'_, K, V, Map>
+where
+    K: Serialize,
+    Map: MapIteration<K, usize>,
+{
+    /// # Errors
+    ///
+    /// Fails if the [`Handle`] does not belong to the map. Any errors from the serializer are
+    /// passed through.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.map
+            .key_of(self.handle)
+            .ok_or_else(|| S::Error::custom("handle does not belong to the map"))?
+            .serialize(serializer)
+    }
+}
+
+/// A [`DeserializeSeed`](serde::de::DeserializeSeed) that deserializes a key and resolves it
+/// into a [`Handle`] into a borrowed [`ShareMap`].
+///
+/// A [`HandleSeed`] is [`Copy`], so one can be reused for every key of a document.
+///
+/// See the [module](self) documentation for an example.
+pub struct HandleSeed<'a, K, V, Map> {
+    map: &'a ShareMap<K, V, Map>,
+}
+
+impl<'a, K, V, Map> HandleSeed<'a, K, V, Map> {
+    /// Creates a new [`HandleSeed`] resolving keys against `map`.
+    #[must_use]
+    pub fn new(map: &'a ShareMap<K, V, Map>) -> Self {
+        Self { map }
+    }
+}
+
+// manual implementation is necessary because #Derive would require K, V and Map: Clone
+impl<K, V, Map> Clone for HandleSeed<'_, K, V, Map> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, Map> Copy for HandleSeed<'_, K, V, Map> {}
+
+impl<K, V, Map> Debug for HandleSeed<'_, K, V, Map> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandleSeed").finish_non_exhaustive()
+    }
+}
+
+impl<'de, K, V, Map> serde::de::DeserializeSeed<'de> for HandleSeed<'_, K, V, Map>
+where
+    Map: MapQuery<str, usize>,
+{
+    type Value = Handle<V>;
+
+    /// # Errors
+    ///
+    /// Fails if the input is not a string, or if the key is not in the map. Any errors from the
+    /// deserializer are passed through.
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<K, V, Map> serde::de::Visitor<'_> for HandleSeed<'_, K, V, Map>
+where
+    Map: MapQuery<str, usize>,
+{
+    type Value = Handle<V>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a key of the map")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.map
+            .get_handle(key)
+            .ok_or_else(|| E::custom(format_args!("unknown key `{key}`")))
+    }
+}
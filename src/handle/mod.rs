@@ -1,4 +1,14 @@
+mod by_address;
 #[allow(clippy::module_inception)]
 mod handle;
+mod handle_batch;
+#[cfg(feature = "serde")]
+pub mod handle_ref;
+mod slice_handle;
+mod store;
 
+pub use by_address::ByAddress;
 pub use handle::Handle;
+pub use handle_batch::HandleBatch;
+pub use slice_handle::{SliceHandle, SliceHandleIter};
+pub(crate) use store::{Store, try_unwrap_store};
@@ -0,0 +1,293 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::ops::{Deref, Range};
+
+use crate::Handle;
+use crate::handle::Store;
+
+/// An immutable reference to a contiguous run of shared values.
+///
+/// Like [`Handle`], a [`SliceHandle`] keeps the value store it points into alive, and can be
+/// cheaply cloned. It implements most common traits via deref to the referenced slice.
+///
+/// Because [`SliceHandle`] derefs to `[T]`, its own methods are associated functions, to avoid
+/// shadowing the methods of the slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::{ShareMap, SliceHandle};
+///
+/// let data = [("shard1", 1), ("shard2", 2), ("shard3", 3), ("other", 4)];
+/// let map = ShareMap::<_, _>::try_from_iter(data)?;
+///
+/// let shards: SliceHandle<i32> = map.get_slice_handle("shard1", "shard3").ok_or("Key not found")?;
+/// assert_eq!(*shards, [1, 2, 3]);
+///
+/// // slice handle is still valid after map is dropped
+/// drop(map);
+/// assert_eq!(shards.iter().sum::<i32>(), 6);
+/// # Ok(())
+/// # }
+/// ```
+pub struct SliceHandle<T> {
+    store: Store<T>,
+    range: Range<usize>,
+}
+
+impl<T> SliceHandle<T> {
+    pub(crate) fn new(store: Store<T>, range: Range<usize>) -> Self {
+        debug_assert!(range.start <= range.end && range.end <= store.len());
+        Self { store, range }
+    }
+
+    /// Returns a [`Handle`] to the value at `index` within the slice, if it is in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, SliceHandle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2), ("key3", 3)])?;
+    /// let slice = map.get_slice_handle("key2", "key3").ok_or("Key not found")?;
+    ///
+    /// let handle = SliceHandle::get(&slice, 0).ok_or("Out of bounds")?;
+    /// assert_eq!(*handle, 2);
+    /// assert!(SliceHandle::get(&slice, 2).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get(this: &Self, index: usize) -> Option<Handle<T>> {
+        match index < this.range.len() {
+            true => Some(Handle::new(this.store.clone(), this.range.start + index)),
+            false => None,
+        }
+    }
+
+    /// Divides the [`SliceHandle`] into two at `mid`, sharing the same value store.
+    ///
+    /// The first will contain the values in `[0, mid)` and the second the values in
+    /// `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, SliceHandle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2), ("key3", 3)])?;
+    /// let slice = map.get_slice_handle("key1", "key3").ok_or("Key not found")?;
+    ///
+    /// let (left, right) = SliceHandle::split_at(slice, 1);
+    /// assert_eq!(*left, [1]);
+    /// assert_eq!(*right, [2, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn split_at(this: Self, mid: usize) -> (Self, Self) {
+        assert!(mid <= this.range.len(), "mid > len");
+
+        let mid = this.range.start + mid;
+        let left = Self::new(this.store.clone(), this.range.start..mid);
+        let right = Self::new(this.store, mid..this.range.end);
+        (left, right)
+    }
+}
+
+impl<T> Clone for SliceHandle<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.store.clone(), self.range.clone())
+    }
+}
+
+impl<T> AsRef<[T]> for SliceHandle<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Borrow<[T]> for SliceHandle<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+/// If `T` implements [Debug], [`SliceHandle`] implements [Debug] by delegating to the derefed
+/// slice.
+impl<T: Debug> Debug for SliceHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Deref for SliceHandle<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // Panic safety: `range` is guaranteed to be in bounds
+        &self.store[self.range.clone()]
+    }
+}
+
+impl<T: Hash> Hash for SliceHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+/// If `T` implements [Eq], [`SliceHandle`] implements equality based on the derefed slice.
+impl<T: Eq> Eq for SliceHandle<T> {}
+
+/// If `T` implements [`PartialEq`], [`SliceHandle`] implements equality based on the derefed
+/// slice.
+impl<T: PartialEq> PartialEq for SliceHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+/// If `T` implements [`PartialOrd`], [`SliceHandle`] implements comparison based on the derefed
+/// slice.
+impl<T: PartialOrd> PartialOrd for SliceHandle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+/// If `T` implements [Ord], [`SliceHandle`] implements comparison based on the derefed slice.
+impl<T: Ord> Ord for SliceHandle<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+/// If `T` implements [`serde::Serialize`], [`SliceHandle`] implements [`serde::Serialize`] by
+/// delegating to the derefed slice. Deserialization is not supported.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for SliceHandle<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (**self).serialize(serializer)
+    }
+}
+
+/// If `T` implements [`schemars::JsonSchema`], [`SliceHandle`] has the schema of an array of `T`,
+/// matching its [`serde::Serialize`] implementation.
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for SliceHandle<T> {
+    fn inline_schema() -> bool {
+        <[T]>::inline_schema()
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        <[T]>::schema_name()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        <[T]>::schema_id()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <[T]>::json_schema(generator)
+    }
+}
+
+impl<T> IntoIterator for SliceHandle<T> {
+    type Item = Handle<T>;
+    type IntoIter = SliceHandleIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SliceHandleIter {
+            store: self.store,
+            range: self.range,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SliceHandle<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An owning iterator over the values of a [`SliceHandle`], yielding a [`Handle`] to each value.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::{Handle, ShareMap};
+///
+/// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+/// let slice = map.get_slice_handle("key1", "key2").ok_or("Key not found")?;
+///
+/// let handles: Vec<Handle<i32>> = slice.into_iter().collect();
+/// assert_eq!(*handles[0], 1);
+/// assert_eq!(*handles[1], 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct SliceHandleIter<T> {
+    store: Store<T>,
+    range: Range<usize>,
+}
+
+impl<T> Clone for SliceHandleIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<T> Debug for SliceHandleIter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SliceHandleIter").finish_non_exhaustive()
+    }
+}
+
+impl<T> Iterator for SliceHandleIter<T> {
+    type Item = Handle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range
+            .next()
+            .map(|index| Handle::new(self.store.clone(), index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceHandleIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range
+            .next_back()
+            .map(|index| Handle::new(self.store.clone(), index))
+    }
+}
+
+impl<T> ExactSizeIterator for SliceHandleIter<T> {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<T> FusedIterator for SliceHandleIter<T> {}
@@ -0,0 +1,83 @@
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use fluent_result::into::IntoResult;
+
+/// The shared value store of a [`ShareMap`](crate::ShareMap).
+///
+/// The values live in an `Arc<[T]>`, which is kept behind a thin reference counted header. A
+/// pointer to the store is then a single word, so a [`Handle`](crate::Handle) is only the store
+/// and a pointer to its value.
+pub(crate) struct Store<T>(Arc<Arc<[T]>>);
+
+impl<T> Store<T> {
+    /// Returns the values of the store.
+    pub(crate) fn values(&self) -> &Arc<[T]> {
+        &self.0
+    }
+
+    /// Returns the values of the store, without cloning them.
+    pub(crate) fn into_values(self) -> Arc<[T]> {
+        Arc::unwrap_or_clone(self.0)
+    }
+}
+
+// manual implementation is necessary because #Derive would require T: Clone
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Deref for Store<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Default for Store<T> {
+    fn default() -> Self {
+        Self(Arc::new(Arc::default()))
+    }
+}
+
+impl<T> From<Vec<T>> for Store<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(Arc::new(values.into()))
+    }
+}
+
+impl<T> FromIterator<T> for Store<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Arc::new(iter.into_iter().collect()))
+    }
+}
+
+/// Attempts to move the values out of the store, succeeding only if it is the sole reference.
+pub(crate) fn try_unwrap_store<T>(store: Store<T>) -> Result<Vec<T>, Store<T>> {
+    let mut values = match Arc::try_unwrap(store.0) {
+        Ok(values) => values,
+        Err(header) => return Err(Store(header)),
+    };
+
+    if Arc::get_mut(&mut values).is_none() {
+        return Err(Store(Arc::new(values)));
+    }
+
+    // SAFETY: `ManuallyDrop<T>` is `repr(transparent)`, so `[T]` and `[ManuallyDrop<T>]` have the
+    // same layout, and the length metadata is preserved by the pointer cast.
+    let mut store = unsafe { Arc::from_raw(Arc::into_raw(values) as *const [ManuallyDrop<T>]) };
+
+    // PANIC SAFETY: uniqueness was checked above, and no other reference can have been created
+    let values = Arc::get_mut(&mut store).expect("store should be unique");
+
+    // SAFETY: each value is taken exactly once, and the store will not drop them afterwards
+    values
+        .iter_mut()
+        .map(|value| unsafe { ManuallyDrop::take(value) })
+        .collect::<Vec<_>>()
+        .into_ok()
+}
@@ -5,12 +5,26 @@
 #![allow(clippy::multiple_crate_versions)]
 
 mod handle;
+mod map;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod share_map;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
 
-pub use handle::Handle;
-pub use share_map::{DuplicateKeyError, Iter, ShareMap};
+pub use handle::{ByAddress, Handle, HandleBatch, SliceHandle, SliceHandleIter};
+pub use map::{BatchMapQuery, KeyValueMapQuery, OrderedMapQuery, StreamingMap};
+pub use share_map::{
+    DuplicateKeyError, Handles, InsertionOrderIter, IntoIter, Iter, KeySpec, Keys,
+    MissingKeysError, ShareMap, ShareMapView, Values,
+};
 
 #[cfg(feature = "serde")]
-pub use share_map::ensure_unqiue;
+pub use handle::handle_ref;
+#[cfg(feature = "rkyv")]
+pub use share_map::{ArchiveOrd, ArchivedShareMap, ShareMapResolver};
+#[cfg(feature = "serde")]
+#[allow(deprecated)]
+pub use share_map::{ensure_unique, ensure_unqiue};
 
 pub use frozen_collections::{Len, MapIteration, MapQuery};
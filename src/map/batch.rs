@@ -0,0 +1,65 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use frozen_collections::{FzHashMap, FzOrderedMap, FzScalarMap, FzStringMap, MapQuery};
+
+#[cfg(doc)]
+use crate::ShareMap;
+
+/// Batched lookups for map types, looking up several keys at once.
+///
+/// Implementing this trait for the `Map` type of a [`ShareMap`] enables [`ShareMap::get_many`]
+/// and [`ShareMap::get_many_handles`]. The provided implementation looks up each key in turn
+/// via [`MapQuery::get`]; map types that can probe several keys more efficiently at once can
+/// override it. It is implemented for [`HashMap`], [`BTreeMap`] and the `Fz*` maps from
+/// [`frozen_collections`], for every key type they can be queried by.
+pub trait BatchMapQuery<Q: ?Sized, V>: MapQuery<Q, V> {
+    /// Returns the values associated with each of `keys`, in the same order.
+    fn get_many<const N: usize>(&self, keys: [&Q; N]) -> [Option<&V>; N] {
+        keys.map(|key| self.get(key))
+    }
+}
+
+impl<K, V, Q, S> BatchMapQuery<Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: ?Sized + Hash + Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, Q> BatchMapQuery<Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: ?Sized + Ord,
+{
+}
+
+impl<K, V, Q, S> BatchMapQuery<Q, V> for FzHashMap<K, V, S>
+where
+    Q: ?Sized,
+    Self: MapQuery<Q, V>,
+{
+}
+
+impl<K, V, Q> BatchMapQuery<Q, V> for FzOrderedMap<K, V>
+where
+    Q: ?Sized,
+    Self: MapQuery<Q, V>,
+{
+}
+
+impl<K, V, Q> BatchMapQuery<Q, V> for FzScalarMap<K, V>
+where
+    Q: ?Sized,
+    Self: MapQuery<Q, V>,
+{
+}
+
+impl<K, V, Q, S> BatchMapQuery<Q, V> for FzStringMap<K, V, S>
+where
+    Q: ?Sized,
+    Self: MapQuery<Q, V>,
+{
+}
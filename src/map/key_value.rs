@@ -0,0 +1,80 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use frozen_collections::{FzHashMap, FzOrderedMap, FzScalarMap, FzStringMap, Scalar};
+
+#[cfg(doc)]
+use crate::ShareMap;
+
+/// Lookups that return the stored key along with the value, for map types that keep their keys.
+///
+/// Implementing this trait for the `Map` type of a [`ShareMap`] enables
+/// [`ShareMap::get_key_value`] and [`ShareMap::get_key_handle`]. It is implemented for
+/// [`HashMap`], [`BTreeMap`] and the `Fz*` maps from [`frozen_collections`], and can be
+/// implemented for other map types.
+///
+/// The [`frozen_collections`] maps are queried by their own key type.
+pub trait KeyValueMapQuery<K, Q: ?Sized, V> {
+    /// Returns the stored key and the value associated with `key`, if it exists.
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)>;
+}
+
+impl<K, V, Q, S> KeyValueMapQuery<K, Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: ?Sized + Hash + Eq,
+    S: BuildHasher,
+{
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        HashMap::get_key_value(self, key)
+    }
+}
+
+impl<K, V, Q> KeyValueMapQuery<K, Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: ?Sized + Ord,
+{
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        BTreeMap::get_key_value(self, key)
+    }
+}
+
+impl<K, V, S> KeyValueMapQuery<K, K, V> for FzHashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        FzHashMap::get_key_value(self, key)
+    }
+}
+
+impl<K, V> KeyValueMapQuery<K, K, V> for FzOrderedMap<K, V>
+where
+    K: Ord,
+{
+    fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        FzOrderedMap::get_key_value(self, key)
+    }
+}
+
+impl<K, V> KeyValueMapQuery<K, K, V> for FzScalarMap<K, V>
+where
+    K: Scalar,
+{
+    fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        FzScalarMap::get_key_value(self, key)
+    }
+}
+
+impl<K, V, S> KeyValueMapQuery<K, K, V> for FzStringMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        FzStringMap::get_key_value(self, key)
+    }
+}
@@ -0,0 +1,9 @@
+mod batch;
+mod key_value;
+mod ordered;
+mod streaming;
+
+pub use batch::BatchMapQuery;
+pub use key_value::KeyValueMapQuery;
+pub use ordered::OrderedMapQuery;
+pub use streaming::StreamingMap;
@@ -0,0 +1,63 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Range;
+use std::ops::RangeBounds;
+
+#[cfg(doc)]
+use crate::ShareMap;
+
+/// Ordered navigation queries for map types that keep their keys sorted.
+///
+/// Implementing this trait for the `Map` type of a [`ShareMap`] enables [`ShareMap::range`],
+/// [`ShareMap::floor`], [`ShareMap::ceiling`] and the other ordered queries. It is implemented
+/// for [`BTreeMap`] only, and can be implemented for other ordered map types. The frozen maps,
+/// including [`FzOrderedMap`](frozen_collections::FzOrderedMap), expose no range lookups and do
+/// not implement it.
+pub trait OrderedMapQuery<K, V> {
+    /// The iterator returned by [`OrderedMapQuery::range`].
+    type RangeIterator<'a>: DoubleEndedIterator<Item = (&'a K, &'a V)>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    /// Returns an iterator over the entries whose keys are within `range`, in ascending key
+    /// order.
+    fn range<Q, R>(&self, range: R) -> Self::RangeIterator<'_>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>;
+
+    /// Returns the entry with the smallest key, if any.
+    fn first_key_value(&self) -> Option<(&K, &V)>;
+
+    /// Returns the entry with the largest key, if any.
+    fn last_key_value(&self) -> Option<(&K, &V)>;
+}
+
+impl<K: Ord, V> OrderedMapQuery<K, V> for BTreeMap<K, V> {
+    type RangeIterator<'a>
+        = Range<'a, K, V>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn range<Q, R>(&self, range: R) -> Self::RangeIterator<'_>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        BTreeMap::range(self, range)
+    }
+
+    fn first_key_value(&self) -> Option<(&K, &V)> {
+        BTreeMap::first_key_value(self)
+    }
+
+    fn last_key_value(&self) -> Option<(&K, &V)> {
+        BTreeMap::last_key_value(self)
+    }
+}
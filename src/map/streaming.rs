@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use frozen_collections::{FzHashMap, FzOrderedMap, FzScalarMap, FzStringMap, MapQuery};
+
+#[cfg(doc)]
+use crate::ShareMap;
+
+/// Map types that can be built one entry at a time, looking up the keys inserted so far.
+///
+/// Implementing this trait for the `Map` type of a [`ShareMap`] enables deserializing it, with
+/// each entry inserted into the index as it arrives so duplicate keys are found immediately. It
+/// is implemented for [`HashMap`] and [`BTreeMap`], which are their own builders, and for the
+/// `Fz*` maps from [`frozen_collections`], which cannot be extended and are built through a
+/// [`HashMap`] or [`BTreeMap`] instead.
+pub trait StreamingMap<K, V>: Sized {
+    /// The map entries are inserted into while building.
+    type Builder: Default + Extend<(K, V)> + MapQuery<K, V>;
+
+    /// Converts the finished builder into the map.
+    fn build(builder: Self::Builder) -> Self;
+}
+
+impl<K, V, S> StreamingMap<K, V> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Builder = Self;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder
+    }
+}
+
+impl<K, V> StreamingMap<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Builder = Self;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder
+    }
+}
+
+impl<K, V, S> StreamingMap<K, V> for FzHashMap<K, V, S>
+where
+    K: Hash + Eq,
+    Self: FromIterator<(K, V)>,
+{
+    type Builder = HashMap<K, V>;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder.into_iter().collect()
+    }
+}
+
+impl<K, V> StreamingMap<K, V> for FzOrderedMap<K, V>
+where
+    K: Ord,
+    Self: FromIterator<(K, V)>,
+{
+    type Builder = BTreeMap<K, V>;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder.into_iter().collect()
+    }
+}
+
+impl<K, V> StreamingMap<K, V> for FzScalarMap<K, V>
+where
+    K: Ord,
+    Self: FromIterator<(K, V)>,
+{
+    type Builder = BTreeMap<K, V>;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder.into_iter().collect()
+    }
+}
+
+impl<K, V, S> StreamingMap<K, V> for FzStringMap<K, V, S>
+where
+    K: Hash + Eq,
+    Self: FromIterator<(K, V)>,
+{
+    type Builder = HashMap<K, V>;
+
+    fn build(builder: Self::Builder) -> Self {
+        builder.into_iter().collect()
+    }
+}
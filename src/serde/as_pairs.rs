@@ -0,0 +1,132 @@
+//! Serializes a [`ShareMap`] as a sequence of `[key, value]` pairs, instead of as a map.
+//!
+//! This supports key types that a format cannot use as map keys, such as integers, tuples or
+//! structs in JSON. Pairs are written in insertion order. Deserialization enforces that all keys
+//! are unique, like [`ensure_unique`](super::ensure_unique).
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use share_map::ShareMap;
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Grid {
+//!     #[serde(with = "share_map::serde::as_pairs")]
+//!     cells: ShareMap<(u8, u8), char>,
+//! }
+//!
+//! let grid = Grid { cells: ShareMap::try_from_iter([((0, 0), 'a'), ((0, 1), 'b')])? };
+//!
+//! let json = serde_json::to_string(&grid)?;
+//! assert_eq!(json, r#"{"cells":[[[0,0],"a"],[[0,1],"b"]]}"#);
+//!
+//! let grid: Grid = serde_json::from_str(&json)?;
+//! assert_eq!(grid.cells.get(&(0, 1)), Some(&'b'));
+//!
+//! // duplicate keys are a data error
+//! let err = serde_json::from_str::<Grid>(r#"{"cells":[[[0,0],"a"],[[0,0],"b"]]}"#)
+//!     .expect_err("should Err");
+//! assert!(err.is_data());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+
+use tap::Pipe;
+
+use crate::{Len, MapIteration, ShareMap};
+
+/// Serializes the map as a sequence of `[key, value]` pairs, in insertion order.
+///
+/// # Errors
+///
+/// Any errors from the serializer are passed through.
+pub fn serialize<S, K, V, Map>(
+    value: &ShareMap<K, V, Map>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+    K: ::serde::Serialize,
+    V: ::serde::Serialize,
+    Map: MapIteration<K, usize>,
+{
+    serializer.collect_seq(value.iter_insertion_order())
+}
+
+/// Deserializes a sequence of `[key, value]` pairs into a [`ShareMap`].
+///
+/// # Errors
+///
+/// Returns a [`serde::de::Error`](::serde::de::Error) if the sequence contains duplicate keys.
+pub fn deserialize<'de, D, K, V, Map>(deserializer: D) -> Result<ShareMap<K, V, Map>, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+    K: ::serde::Deserialize<'de>,
+    V: ::serde::Deserialize<'de>,
+    Map: FromIterator<(K, usize)> + Len,
+{
+    deserializer.deserialize_seq(PairsVisitor(PhantomData))
+}
+
+/// Generates the schema of a sequence of `[key, value]` pairs, annotated with
+/// `"uniqueKeys": true`. Use this with `#[schemars(schema_with = "...")]`.
+///
+/// # Example
+///
+/// ```rust
+/// use share_map::ShareMap;
+///
+/// #[derive(schemars::JsonSchema)]
+/// struct Grid {
+///     #[schemars(schema_with = "share_map::serde::as_pairs::json_schema::<(u8, u8), char>")]
+///     cells: ShareMap<(u8, u8), char>,
+/// }
+///
+/// let schema = schemars::schema_for!(Grid);
+/// let cells = schema.get("properties").and_then(|properties| properties.get("cells"));
+/// assert_eq!(cells.and_then(|cells| cells.get("type")), Some(&serde_json::json!("array")));
+/// ```
+#[cfg(feature = "schemars")]
+pub fn json_schema<K, V>(generator: &mut schemars::SchemaGenerator) -> schemars::Schema
+where
+    K: schemars::JsonSchema,
+    V: schemars::JsonSchema,
+{
+    schemars::json_schema!({
+        "type": "array",
+        "items": generator.subschema_for::<(K, V)>(),
+        "uniqueKeys": true,
+    })
+}
+
+#[derive(Debug)]
+struct PairsVisitor<K, V, Map>(PhantomData<ShareMap<K, V, Map>>);
+
+impl<'de, K, V, Map> ::serde::de::Visitor<'de> for PairsVisitor<K, V, Map>
+where
+    K: ::serde::Deserialize<'de>,
+    V: ::serde::Deserialize<'de>,
+    Map: FromIterator<(K, usize)> + Len,
+{
+    type Value = ShareMap<K, V, Map>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence of key-value pairs with unique keys")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: ::serde::de::SeqAccess<'de>,
+    {
+        let mut entries = access.size_hint().unwrap_or(0).pipe(Vec::with_capacity);
+
+        while let Some(entry) = access.next_element::<(K, V)>()? {
+            entries.push(entry);
+        }
+
+        ShareMap::try_from_iter(entries).map_err(::serde::de::Error::custom)
+    }
+}
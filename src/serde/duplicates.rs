@@ -0,0 +1,156 @@
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+use crate::{DuplicateKeyError, MapQuery, ShareMap, StreamingMap};
+
+/// Marks a type that produces a [`ShareMap`], without owning one.
+type Produces<K, V, Map> = PhantomData<fn() -> ShareMap<K, V, Map>>;
+
+/// How duplicate keys are handled when deserializing a [`ShareMap`].
+///
+/// Duplicates are found as entries arrive, by looking each key up in the index built so far, so
+/// the policy does not depend on how `Map` itself handles duplicate keys. A kept value takes the
+/// position of the key's first occurrence in the value store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// Fail with a data error if any key is duplicated. This is what
+    /// [`ensure_unique`](super::ensure_unique) uses.
+    Reject,
+    /// Keep the value of the first occurrence of a duplicated key.
+    KeepFirst,
+    /// Keep the value of the last occurrence of a duplicated key, replacing earlier values as
+    /// they are found. This is what
+    /// [`ShareMap`]'s [`Deserialize`](::serde::Deserialize) implementation uses.
+    #[default]
+    KeepLast,
+}
+
+/// A [`DeserializeSeed`](::serde::de::DeserializeSeed) that deserializes a [`ShareMap`] with a
+/// chosen [`DuplicatePolicy`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use serde::de::DeserializeSeed;
+/// use share_map::ShareMap;
+/// use share_map::serde::{DuplicatePolicy, ShareMapSeed};
+///
+/// let json = r#"{"key1": 1, "key2": 2, "key1": 3}"#;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let map: ShareMap<String, u8> =
+///     ShareMapSeed::new(DuplicatePolicy::KeepFirst).deserialize(&mut deserializer)?;
+/// assert_eq!(map.get("key1"), Some(&1));
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let result: Result<ShareMap<String, u8>, _> =
+///     ShareMapSeed::new(DuplicatePolicy::Reject).deserialize(&mut deserializer);
+/// assert!(result.is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShareMapSeed<K, V, Map> {
+    policy: DuplicatePolicy,
+    _marker: Produces<K, V, Map>,
+}
+
+impl<K, V, Map> ShareMapSeed<K, V, Map> {
+    /// Creates a new [`ShareMapSeed`] with the given [`DuplicatePolicy`].
+    #[must_use]
+    pub fn new(policy: DuplicatePolicy) -> Self {
+        Self {
+            policy,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, Map> Clone for ShareMapSeed<K, V, Map> {
+    fn clone(&self) -> Self {
+        Self::new(self.policy)
+    }
+}
+
+impl<K, V, Map> Debug for ShareMapSeed<K, V, Map> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShareMapSeed")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'de, K, V, Map> ::serde::de::DeserializeSeed<'de> for ShareMapSeed<K, V, Map>
+where
+    K: ::serde::Deserialize<'de>,
+    V: ::serde::Deserialize<'de>,
+    Map: StreamingMap<K, usize>,
+{
+    type Value = ShareMap<K, V, Map>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ShareMapVisitor::new(self.policy))
+    }
+}
+
+/// Streams map entries directly into the value store and index of a [`ShareMap`].
+pub(crate) struct ShareMapVisitor<K, V, Map> {
+    policy: DuplicatePolicy,
+    _marker: Produces<K, V, Map>,
+}
+
+impl<K, V, Map> ShareMapVisitor<K, V, Map> {
+    pub(crate) fn new(policy: DuplicatePolicy) -> Self {
+        Self {
+            policy,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, K, V, Map> ::serde::de::Visitor<'de> for ShareMapVisitor<K, V, Map>
+where
+    K: ::serde::Deserialize<'de>,
+    V: ::serde::Deserialize<'de>,
+    Map: StreamingMap<K, usize>,
+{
+    type Value = ShareMap<K, V, Map>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.policy {
+            DuplicatePolicy::Reject => formatter.write_str("a map with unique keys"),
+            DuplicatePolicy::KeepFirst | DuplicatePolicy::KeepLast => formatter.write_str("a map"),
+        }
+    }
+
+    fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+    where
+        M: ::serde::de::MapAccess<'de>,
+    {
+        let mut index_map = Map::Builder::default();
+        let mut values = access.size_hint().unwrap_or(0).pipe(Vec::with_capacity);
+
+        while let Some(key) = access.next_key()? {
+            match (index_map.get(&key).copied(), self.policy) {
+                (None, _) => {
+                    index_map.extend([(key, values.len())]);
+                    values.push(access.next_value()?);
+                }
+                (Some(_), DuplicatePolicy::Reject) => {
+                    return Err(::serde::de::Error::custom(DuplicateKeyError));
+                }
+                // the value is still deserialized, so it must be valid even though it is dropped
+                (Some(_), DuplicatePolicy::KeepFirst) => drop(access.next_value::<V>()?),
+                (Some(index), DuplicatePolicy::KeepLast) => values[index] = access.next_value()?,
+            }
+        }
+
+        ShareMap::new(Map::build(index_map), values.into()).into_ok()
+    }
+}
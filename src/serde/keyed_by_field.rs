@@ -0,0 +1,145 @@
+//! (De)serializes a [`ShareMap`] as a sequence of its values, with each value's key extracted
+//! from the value itself by [`KeyedBy`].
+//!
+//! This supports data shaped like `[{"id": "a", ...}, {"id": "b", ...}]`. Values are written in
+//! insertion order. Deserialization fails with a data error naming the first duplicated key.
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use share_map::ShareMap;
+//! use share_map::serde::KeyedBy;
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct User {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! impl KeyedBy<String> for User {
+//!     fn key(&self) -> String {
+//!         self.id.clone()
+//!     }
+//! }
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Directory {
+//!     #[serde(with = "share_map::serde::keyed_by_field")]
+//!     users: ShareMap<String, User>,
+//! }
+//!
+//! let json = r#"{"users":[{"id":"a","name":"Ada"},{"id":"b","name":"Bob"}]}"#;
+//! let directory: Directory = serde_json::from_str(json)?;
+//! assert_eq!(directory.users.get("b").map(|user| user.name.as_str()), Some("Bob"));
+//!
+//! // serialization writes the values back out as a sequence
+//! assert_eq!(serde_json::to_string(&directory)?, json);
+//!
+//! // duplicate keys are a data error naming the key
+//! let err = serde_json::from_str::<Directory>(
+//!     r#"{"users":[{"id":"a","name":"Ada"},{"id":"a","name":"Alan"}]}"#,
+//! )
+//! .expect_err("should Err");
+//! assert!(err.is_data());
+//! assert!(err.to_string().contains(r#"duplicate key "a""#));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use tap::Pipe;
+
+use crate::{Len, MapQuery, ShareMap};
+
+/// A value that contains its own key, typically in an `id`-like field.
+///
+/// Used by the [`keyed_by_field`](self) serde adapter.
+pub trait KeyedBy<K> {
+    /// Returns the key of this value.
+    fn key(&self) -> K;
+}
+
+/// Serializes the map as a sequence of its values, in insertion order.
+///
+/// # Errors
+///
+/// Any errors from the serializer are passed through.
+pub fn serialize<S, K, V, Map>(
+    value: &ShareMap<K, V, Map>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+    V: ::serde::Serialize,
+{
+    serializer.collect_seq(value.values())
+}
+
+/// Deserializes a sequence of values into a [`ShareMap`], keying each value by [`KeyedBy::key`].
+///
+/// # Errors
+///
+/// Returns a [`serde::de::Error`](::serde::de::Error) naming the first duplicated key, if any key
+/// is duplicated.
+pub fn deserialize<'de, D, K, V, Map>(deserializer: D) -> Result<ShareMap<K, V, Map>, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+    K: Debug,
+    V: ::serde::Deserialize<'de> + KeyedBy<K>,
+    Map: FromIterator<(K, usize)> + Len + MapQuery<K, usize>,
+{
+    deserializer.deserialize_seq(KeyedVisitor(PhantomData))
+}
+
+#[derive(Debug)]
+struct KeyedVisitor<K, V, Map>(PhantomData<ShareMap<K, V, Map>>);
+
+impl<'de, K, V, Map> ::serde::de::Visitor<'de> for KeyedVisitor<K, V, Map>
+where
+    K: Debug,
+    V: ::serde::Deserialize<'de> + KeyedBy<K>,
+    Map: FromIterator<(K, usize)> + Len + MapQuery<K, usize>,
+{
+    type Value = ShareMap<K, V, Map>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence of values with unique keys")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: ::serde::de::SeqAccess<'de>,
+    {
+        let mut values = access.size_hint().unwrap_or(0).pipe(Vec::with_capacity);
+
+        while let Some(value) = access.next_element::<V>()? {
+            values.push(value);
+        }
+
+        let index_map: Map = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (value.key(), index))
+            .collect();
+
+        if index_map.len() != values.len() {
+            // the map keeps the last of duplicate keys, so the first mismatch is a duplicate
+            let duplicate = values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (value.key(), index))
+                .find(|(key, index)| index_map.get(key) != Some(index))
+                .map(|(key, _)| key);
+
+            return Err(match duplicate {
+                Some(key) => ::serde::de::Error::custom(format_args!("duplicate key {key:?}")),
+                None => ::serde::de::Error::custom(crate::DuplicateKeyError),
+            });
+        }
+
+        Ok(ShareMap::new(index_map, values.into()))
+    }
+}
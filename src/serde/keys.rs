@@ -0,0 +1,63 @@
+//! Deserializes a [`ShareMap`] with string-like keys, enforcing that its keys satisfy a
+//! [`KeySpec`].
+//!
+//! Deserialization fails with a data error listing all missing and unexpected keys, see
+//! [`MissingKeysError`](crate::MissingKeysError). Like [`ensure_unique`](super::ensure_unique),
+//! duplicate keys are also rejected. Because the [`KeySpec`] cannot be inferred, use this with
+//! `#[serde(deserialize_with = "...")]`, naming the spec.
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use share_map::{KeySpec, ShareMap};
+//!
+//! struct ServerKeys;
+//!
+//! impl KeySpec for ServerKeys {
+//!     const REQUIRED: &'static [&'static str] = &["host", "port"];
+//!     const ALLOWED: Option<&'static [&'static str]> = Some(&["timeout"]);
+//! }
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Server {
+//!     #[serde(deserialize_with = "share_map::serde::keys::deserialize::<ServerKeys, _, _, _, _>")]
+//!     settings: ShareMap<String, String>,
+//! }
+//!
+//! let server: Server = serde_json::from_str(r#"{"settings":{"host":"localhost","port":"80"}}"#)?;
+//! assert_eq!(server.settings.get("port").map(String::as_str), Some("80"));
+//!
+//! let err = serde_json::from_str::<Server>(r#"{"settings":{"host":"localhost","retries":"3"}}"#)
+//!     .expect_err("should Err");
+//! assert!(err.is_data());
+//! assert!(err.to_string().contains(r#"missing keys ["port"], unexpected keys ["retries"]"#));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::serde::{DuplicatePolicy, ShareMapVisitor};
+use crate::{KeySpec, MapIteration, MapQuery, ShareMap, StreamingMap};
+
+/// Deserializes the data into a [`ShareMap`], then checks its keys against the [`KeySpec`] `S`.
+///
+/// # Errors
+///
+/// Returns a [`serde::de::Error`](::serde::de::Error) if the map contains duplicate keys, or if
+/// its keys do not satisfy `S`.
+pub fn deserialize<'de, S, D, K, V, Map>(deserializer: D) -> Result<ShareMap<K, V, Map>, D::Error>
+where
+    S: KeySpec,
+    D: ::serde::Deserializer<'de>,
+    K: ::serde::Deserialize<'de> + Borrow<str>,
+    V: ::serde::Deserialize<'de>,
+    Map: StreamingMap<K, usize> + MapIteration<K, usize> + MapQuery<str, usize>,
+{
+    let map = deserializer.deserialize_map(ShareMapVisitor::new(DuplicatePolicy::Reject))?;
+    map.require_keys::<S>()
+        .map_err(::serde::de::Error::custom)?;
+
+    Ok(map)
+}
@@ -0,0 +1,14 @@
+//! Serde adapters for [`ShareMap`](crate::ShareMap), for use with `#[serde(with = "...")]`.
+
+pub mod as_pairs;
+mod duplicates;
+pub mod keyed_by_field;
+pub mod keys;
+pub mod sorted;
+
+pub(crate) use duplicates::ShareMapVisitor;
+pub use duplicates::{DuplicatePolicy, ShareMapSeed};
+pub use keyed_by_field::KeyedBy;
+
+#[allow(deprecated)]
+pub use crate::share_map::{ensure_unique, ensure_unqiue};
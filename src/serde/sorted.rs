@@ -0,0 +1,53 @@
+//! Serializes a [`ShareMap`] with its entries sorted by key, for deterministic output regardless
+//! of the `Map` implementation.
+//!
+//! Deserialization enforces that all keys are unique, like
+//! [`ensure_unique`](super::ensure_unique).
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use share_map::ShareMap;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Cache {
+//!     #[serde(with = "share_map::serde::sorted")]
+//!     entries: ShareMap<String, u32>,
+//! }
+//!
+//! let data = [("zeta", 3), ("alpha", 1), ("mu", 2)].map(|(k, v)| (k.to_string(), v));
+//! let cache = Cache { entries: ShareMap::try_from_iter(data)? };
+//!
+//! let json = serde_json::to_string(&cache)?;
+//! assert_eq!(json, r#"{"entries":{"alpha":1,"mu":2,"zeta":3}}"#);
+//!
+//! let cache: Cache = serde_json::from_str(&json)?;
+//! assert_eq!(cache.entries.get("mu"), Some(&2));
+//! # Ok(())
+//! # }
+//! ```
+
+pub use super::ensure_unique::deserialize;
+#[cfg(feature = "schemars")]
+pub use super::ensure_unique::json_schema;
+use crate::{MapIteration, ShareMap};
+
+/// Serializes the map with its entries sorted by key, see [`ShareMap::serialize_sorted`].
+///
+/// # Errors
+///
+/// Any errors from the serializer are passed through.
+#[inline]
+pub fn serialize<S, K, V, Map>(
+    value: &ShareMap<K, V, Map>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+    K: ::serde::Serialize + Ord,
+    V: ::serde::Serialize,
+    Map: MapIteration<K, usize>,
+{
+    value.serialize_sorted(serializer)
+}
@@ -1,7 +1,9 @@
-use std::iter::FusedIterator;
+use std::iter::{FusedIterator, Zip};
 
+use crate::Handle;
 #[cfg(doc)]
 use crate::ShareMap;
+use crate::handle::Store;
 
 /// A borrowed iterator over the key-value pairs in a [`ShareMap`].
 ///
@@ -26,7 +28,6 @@ use crate::ShareMap;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone)]
 pub struct Iter<'a, K: 'a, V, I>
 where
     I: Iterator<Item = (&'a K, &'a usize)>,
@@ -35,6 +36,16 @@ where
     store: &'a [V],
 }
 
+// manual implementation is necessary because #Derive would require K: Clone and V: Clone
+impl<'a, K, V, I> Clone for Iter<'a, K, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.index_iter.clone(), self.store)
+    }
+}
+
 impl<'a, K, V, I> std::fmt::Debug for Iter<'a, K, V, I>
 where
     I: Iterator<Item = (&'a K, &'a usize)>,
@@ -70,7 +81,276 @@ where
     }
 }
 
-impl<'a, K, V: Clone, I> ExactSizeIterator for Iter<'a, K, V, I>
+impl<'a, K, V, I> DoubleEndedIterator for Iter<'a, K, V, I>
+where
+    I: DoubleEndedIterator<Item = (&'a K, &'a usize)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.index_iter
+            .next_back()
+            .and_then(|(key, index)| self.store.get(*index).map(|val| (key, val)))
+    }
+}
+
+impl<'a, K, V, I> ExactSizeIterator for Iter<'a, K, V, I>
+where
+    I: ExactSizeIterator<Item = (&'a K, &'a usize)>,
+{
+    fn len(&self) -> usize {
+        self.index_iter.len()
+    }
+}
+
+impl<'a, K, V, I> FusedIterator for Iter<'a, K, V, I> where
+    I: FusedIterator<Item = (&'a K, &'a usize)>
+{
+}
+
+/// A borrowed iterator over the keys in a [`ShareMap`].
+///
+/// Order of iteration is dependent on the underlying map implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::BTreeMap;
+/// use share_map::ShareMap;
+///
+/// let share_map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(15, 42), (23, 100)])?;
+///
+/// let keys: Vec<_> = share_map.keys().rev().collect();
+///
+/// assert_eq!(keys, [&23, &15]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Keys<'a, K: 'a, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    index_iter: I,
+}
+
+impl<'a, K, I> Keys<'a, K, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    pub(crate) fn new(index_iter: I) -> Self {
+        Self { index_iter }
+    }
+}
+
+// manual implementation is necessary because #Derive would require K: Clone
+impl<'a, K, I> Clone for Keys<'a, K, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.index_iter.clone())
+    }
+}
+
+impl<'a, K, I> std::fmt::Debug for Keys<'a, K, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keys").finish_non_exhaustive()
+    }
+}
+
+impl<'a, K, I> Iterator for Keys<'a, K, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index_iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index_iter.size_hint()
+    }
+}
+
+impl<'a, K, I> DoubleEndedIterator for Keys<'a, K, I>
+where
+    I: DoubleEndedIterator<Item = (&'a K, &'a usize)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.index_iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K, I> ExactSizeIterator for Keys<'a, K, I>
+where
+    I: ExactSizeIterator<Item = (&'a K, &'a usize)>,
+{
+    fn len(&self) -> usize {
+        self.index_iter.len()
+    }
+}
+
+impl<'a, K, I> FusedIterator for Keys<'a, K, I> where I: FusedIterator<Item = (&'a K, &'a usize)> {}
+
+/// A borrowed iterator over the values in a [`ShareMap`], in insertion order.
+///
+/// See [`ShareMap::values`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::ShareMap;
+///
+/// let share_map = ShareMap::<_, _>::try_from_iter([(23, 100), (15, 42)])?;
+///
+/// let values: Vec<_> = share_map.values().rev().collect();
+///
+/// assert_eq!(values, [&42, &100]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Values<'a, V> {
+    inner: std::slice::Iter<'a, V>,
+}
+
+impl<'a, V> Values<'a, V> {
+    pub(crate) fn new(store: &'a [V]) -> Self {
+        Self {
+            inner: store.iter(),
+        }
+    }
+}
+
+// manual implementation is necessary because #Derive would require V: Clone
+impl<V> Clone for Values<'_, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<V> std::fmt::Debug for Values<'_, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Values").finish_non_exhaustive()
+    }
+}
+
+impl<'a, V> Iterator for Values<'a, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for Values<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<V> ExactSizeIterator for Values<'_, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<V> FusedIterator for Values<'_, V> {}
+
+/// A borrowed iterator over the key-value pairs in a [`ShareMap`], yielding a [`Handle`] to each
+/// value.
+///
+/// Order of iteration is dependent on the underlying map implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::BTreeMap;
+/// use share_map::{Handle, ShareMap};
+///
+/// let share_map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(15, 42), (23, 100)])?;
+///
+/// let handles: Vec<(&i32, Handle<i32>)> = share_map.handles().collect();
+///
+/// assert_eq!(handles[0].0, &15);
+/// assert_eq!(*handles[0].1, 42);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Handles<'a, K: 'a, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    index_iter: I,
+    store: &'a Store<V>,
+}
+
+impl<'a, K, V, I> Handles<'a, K, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    pub(crate) fn new(index_iter: I, store: &'a Store<V>) -> Self {
+        Self { index_iter, store }
+    }
+}
+
+impl<'a, K, V, I> Clone for Handles<'a, K, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.index_iter.clone(), self.store)
+    }
+}
+
+impl<'a, K, V, I> std::fmt::Debug for Handles<'a, K, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Handles").finish_non_exhaustive()
+    }
+}
+
+impl<'a, K, V, I> Iterator for Handles<'a, K, V, I>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    type Item = (&'a K, Handle<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index_iter
+            .next()
+            .map(|(key, index)| (key, Handle::new(self.store.clone(), *index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index_iter.size_hint()
+    }
+}
+
+impl<'a, K, V, I> DoubleEndedIterator for Handles<'a, K, V, I>
+where
+    I: DoubleEndedIterator<Item = (&'a K, &'a usize)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.index_iter
+            .next_back()
+            .map(|(key, index)| (key, Handle::new(self.store.clone(), *index)))
+    }
+}
+
+impl<'a, K, V, I> ExactSizeIterator for Handles<'a, K, V, I>
 where
     I: ExactSizeIterator<Item = (&'a K, &'a usize)>,
 {
@@ -79,11 +359,178 @@ where
     }
 }
 
-impl<'a, K, V: Clone, I> FusedIterator for Iter<'a, K, V, I> where
+impl<'a, K, V, I> FusedIterator for Handles<'a, K, V, I> where
     I: FusedIterator<Item = (&'a K, &'a usize)>
 {
 }
 
+/// An owning iterator over the key-value pairs in a [`ShareMap`], yielding each key and a
+/// [`Handle`] to its value.
+///
+/// Order of iteration is dependent on the underlying map implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::BTreeMap;
+/// use share_map::{Handle, ShareMap};
+///
+/// let share_map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(15, 42), (23, 100)])?;
+///
+/// let handles: Vec<(i32, Handle<i32>)> = share_map.into_iter().collect();
+///
+/// assert_eq!(handles[1].0, 23);
+/// assert_eq!(*handles[1].1, 100);
+/// # Ok(())
+/// # }
+/// ```
+pub struct IntoIter<K, V, I>
+where
+    I: Iterator<Item = (K, usize)>,
+{
+    index_iter: I,
+    store: Store<V>,
+}
+
+impl<K, V, I> IntoIter<K, V, I>
+where
+    I: Iterator<Item = (K, usize)>,
+{
+    pub(crate) fn new(index_iter: I, store: Store<V>) -> Self {
+        Self { index_iter, store }
+    }
+}
+
+impl<K, V, I> Clone for IntoIter<K, V, I>
+where
+    I: Iterator<Item = (K, usize)> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.index_iter.clone(), self.store.clone())
+    }
+}
+
+impl<K, V, I> std::fmt::Debug for IntoIter<K, V, I>
+where
+    I: Iterator<Item = (K, usize)>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntoIter").finish_non_exhaustive()
+    }
+}
+
+impl<K, V, I> Iterator for IntoIter<K, V, I>
+where
+    I: Iterator<Item = (K, usize)>,
+{
+    type Item = (K, Handle<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index_iter
+            .next()
+            .map(|(key, index)| (key, Handle::new(self.store.clone(), index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index_iter.size_hint()
+    }
+}
+
+impl<K, V, I> DoubleEndedIterator for IntoIter<K, V, I>
+where
+    I: DoubleEndedIterator<Item = (K, usize)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.index_iter
+            .next_back()
+            .map(|(key, index)| (key, Handle::new(self.store.clone(), index)))
+    }
+}
+
+impl<K, V, I> ExactSizeIterator for IntoIter<K, V, I>
+where
+    I: ExactSizeIterator<Item = (K, usize)>,
+{
+    fn len(&self) -> usize {
+        self.index_iter.len()
+    }
+}
+
+impl<K, V, I> FusedIterator for IntoIter<K, V, I> where I: FusedIterator<Item = (K, usize)> {}
+
+/// A borrowed iterator over the key-value pairs in a [`ShareMap`], in insertion order.
+///
+/// See [`ShareMap::iter_insertion_order`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::ShareMap;
+///
+/// let share_map = ShareMap::<_, _>::try_from_iter([(23, 100), (15, 42)])?;
+///
+/// let pairs: Vec<_> = share_map.iter_insertion_order().rev().collect();
+///
+/// assert_eq!(pairs, [(&15, &42), (&23, &100)]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct InsertionOrderIter<'a, K, V> {
+    inner: Zip<std::vec::IntoIter<&'a K>, std::slice::Iter<'a, V>>,
+}
+
+impl<'a, K, V> InsertionOrderIter<'a, K, V> {
+    pub(crate) fn new(keys: Vec<&'a K>, store: &'a [V]) -> Self {
+        debug_assert_eq!(keys.len(), store.len());
+        Self {
+            inner: keys.into_iter().zip(store),
+        }
+    }
+}
+
+// manual implementation is necessary because #Derive would require K: Clone and V: Clone
+impl<K, V> Clone for InsertionOrderIter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K, V> std::fmt::Debug for InsertionOrderIter<'_, K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InsertionOrderIter").finish_non_exhaustive()
+    }
+}
+
+impl<'a, K, V> Iterator for InsertionOrderIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for InsertionOrderIter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<K, V> ExactSizeIterator for InsertionOrderIter<'_, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for InsertionOrderIter<'_, K, V> {}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -100,6 +547,64 @@ mod tests {
         assert_eq!(debug, "Iter { .. }");
     }
 
+    #[test]
+    fn clone_does_not_require_clone_items() {
+        struct NotClone;
+
+        let map = ShareMap::<_, _>::try_from_iter([(15, NotClone)]).expect("should be ok");
+
+        assert_eq!(map.iter().clone().count(), 1);
+        assert_eq!(map.keys().clone().count(), 1);
+        assert_eq!(map.values().clone().count(), 1);
+        assert_eq!(map.iter_insertion_order().clone().count(), 1);
+    }
+
+    #[test]
+    fn handles_debug_is_expected() {
+        let map = ShareMap::<_, _>::try_from_iter([(15, 42), (23, 100)]).expect("should be ok");
+
+        assert_eq!(format!("{:?}", map.handles()), "Handles { .. }");
+        assert_eq!(format!("{:?}", map.keys()), "Keys { .. }");
+        assert_eq!(format!("{:?}", map.values()), "Values { .. }");
+        assert_eq!(
+            format!("{:?}", map.iter_insertion_order()),
+            "InsertionOrderIter { .. }"
+        );
+        assert_eq!(format!("{:?}", map.into_iter()), "IntoIter { .. }");
+    }
+
+    #[test]
+    fn handles_size_hint_len_fused_trait_are_correct() {
+        let map = ShareMap::<_, _>::try_from_iter([(15, 42), (23, 100)]).expect("should be ok");
+        let mut iter = map.handles();
+
+        for len in (1..=2).rev() {
+            assert_eq!(iter.len(), len);
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+
+            iter.next();
+        }
+
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none()); // FusedIterator guarantees this remains None
+    }
+
+    #[test]
+    fn into_iter_size_hint_len_fused_trait_are_correct() {
+        let map = ShareMap::<_, _>::try_from_iter([(15, 42), (23, 100)]).expect("should be ok");
+        let mut iter = map.into_iter();
+
+        for len in (1..=2).rev() {
+            assert_eq!(iter.len(), len);
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+
+            iter.next();
+        }
+
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none()); // FusedIterator guarantees this remains None
+    }
+
     #[test]
     fn borrow_iter_matches_btreemap() {
         let btree_map = BTreeMap::from([("key1", 42), ("key2", 100)]);
@@ -126,4 +631,32 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None); // FusedIterator guarantees this remains None
     }
+
+    #[test]
+    fn ordered_iterators_are_double_ended() {
+        let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(15, 42), (23, 100)])
+            .expect("should be ok");
+
+        assert_eq!(map.iter().next_back(), Some((&23, &100)));
+        assert_eq!(map.keys().next_back(), Some(&23));
+        assert_eq!(map.values().next_back(), Some(&100));
+        assert_eq!(map.handles().next_back().map(|(key, _)| key), Some(&23));
+        assert_eq!(map.into_iter().next_back().map(|(key, _)| key), Some(23));
+    }
+
+    #[test]
+    fn keys_size_hint_len_fused_trait_are_correct() {
+        let map = ShareMap::<_, _>::try_from_iter([(15, 42), (23, 100)]).expect("should be ok");
+        let mut iter = map.keys();
+
+        for len in (1..=2).rev() {
+            assert_eq!(iter.len(), len);
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+
+            iter.next();
+        }
+
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none()); // FusedIterator guarantees this remains None
+    }
 }
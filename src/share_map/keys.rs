@@ -0,0 +1,183 @@
+use std::borrow::Borrow;
+
+use frozen_collections::{MapIteration, MapQuery};
+
+use crate::ShareMap;
+
+/// A specification of the keys a [`ShareMap`] with string-like keys must, may and must not
+/// contain.
+///
+/// Checked by [`ShareMap::require_keys`], and on deserialization by the
+/// [`keys`](crate::serde::keys) serde adapter.
+///
+/// # Example
+///
+/// ```rust
+/// use share_map::KeySpec;
+///
+/// struct ServerKeys;
+///
+/// impl KeySpec for ServerKeys {
+///     const REQUIRED: &'static [&'static str] = &["host", "port"];
+///     const ALLOWED: Option<&'static [&'static str]> = Some(&["timeout"]);
+/// }
+/// ```
+pub trait KeySpec {
+    /// Keys that must be present.
+    const REQUIRED: &'static [&'static str];
+
+    /// If [`Some`], the only keys that may be present in addition to [`KeySpec::REQUIRED`].
+    /// If [`None`], any key not in [`KeySpec::DENIED`] is allowed.
+    const ALLOWED: Option<&'static [&'static str]> = None;
+
+    /// Keys that must not be present.
+    ///
+    /// A key cannot be both required and denied, as no map could satisfy the specification.
+    /// Checking the keys against such a specification fails to compile.
+    ///
+    /// ```rust,compile_fail
+    /// use share_map::{KeySpec, ShareMap};
+    ///
+    /// struct Contradiction;
+    ///
+    /// impl KeySpec for Contradiction {
+    ///     const REQUIRED: &'static [&'static str] = &["host"];
+    ///     const DENIED: &'static [&'static str] = &["host"];
+    /// }
+    ///
+    /// let map = ShareMap::<_, _>::from_iter([("host", "localhost")]);
+    /// let _ = map.require_keys::<Contradiction>();
+    /// ```
+    const DENIED: &'static [&'static str] = &[];
+
+    /// Checks if `key` may be present in a map satisfying this specification.
+    #[must_use]
+    fn is_allowed(key: &str) -> bool {
+        !Self::DENIED.contains(&key)
+            && Self::ALLOWED
+                .is_none_or(|allowed| allowed.contains(&key) || Self::REQUIRED.contains(&key))
+    }
+}
+
+/// Checks if any key is in both `left` and `right`.
+const fn overlaps(left: &[&str], right: &[&str]) -> bool {
+    let mut i = 0;
+    while i < left.len() {
+        let mut j = 0;
+        while j < right.len() {
+            if str_eq(left[i], right[j]) {
+                return true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// `str` equality, usable in const contexts.
+const fn str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// An error returned when a [`ShareMap`]'s keys do not satisfy a [`KeySpec`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("missing keys {missing:?}, unexpected keys {unexpected:?}")]
+pub struct MissingKeysError {
+    missing: Vec<String>,
+    unexpected: Vec<String>,
+}
+
+impl MissingKeysError {
+    /// The required keys that were not present, in the order of [`KeySpec::REQUIRED`].
+    #[must_use]
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+
+    /// The keys that were present but not allowed, sorted.
+    #[must_use]
+    pub fn unexpected(&self) -> &[String] {
+        &self.unexpected
+    }
+}
+
+impl<K, V, Map> ShareMap<K, V, Map> {
+    /// Checks that the map's keys satisfy the [`KeySpec`] `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MissingKeysError`] listing all missing and unexpected keys, if there are any.
+    ///
+    /// Fails to compile if a key of `S` is both required and denied, see [`KeySpec::DENIED`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{KeySpec, ShareMap};
+    ///
+    /// struct ServerKeys;
+    ///
+    /// impl KeySpec for ServerKeys {
+    ///     const REQUIRED: &'static [&'static str] = &["host", "port"];
+    ///     const ALLOWED: Option<&'static [&'static str]> = Some(&["timeout"]);
+    /// }
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("host", "localhost"), ("port", "80")])?;
+    /// map.require_keys::<ServerKeys>()?;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("host", "localhost"), ("retries", "3")])?;
+    /// let err = map.require_keys::<ServerKeys>().expect_err("should Err");
+    /// assert_eq!(err.missing(), ["port"]);
+    /// assert_eq!(err.unexpected(), ["retries"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require_keys<S: KeySpec>(&self) -> Result<(), MissingKeysError>
+    where
+        K: Borrow<str>,
+        Map: MapQuery<str, usize> + MapIteration<K, usize>,
+    {
+        const {
+            assert!(
+                !overlaps(S::REQUIRED, S::DENIED),
+                "a KeySpec key cannot be both required and denied"
+            );
+        }
+
+        let missing: Vec<_> = S::REQUIRED
+            .iter()
+            .filter(|key| !self.contains_key(**key))
+            .map(|key| (*key).to_owned())
+            .collect();
+
+        let mut unexpected: Vec<_> = self
+            .keys()
+            .map(Borrow::<str>::borrow)
+            .filter(|key| !S::is_allowed(key))
+            .map(str::to_owned)
+            .collect();
+        unexpected.sort_unstable();
+
+        match missing.is_empty() && unexpected.is_empty() {
+            true => Ok(()),
+            false => Err(MissingKeysError {
+                missing,
+                unexpected,
+            }),
+        }
+    }
+}
@@ -1,10 +1,21 @@
 mod iter;
+mod keys;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
 mod serde;
 #[allow(clippy::module_inception)]
 mod share_map;
+mod view;
 
-pub use iter::Iter;
+pub use iter::{Handles, InsertionOrderIter, IntoIter, Iter, Keys, Values};
+pub use keys::{KeySpec, MissingKeysError};
+#[cfg(feature = "rkyv")]
+pub use rkyv::{ArchiveOrd, ArchivedShareMap, ShareMapResolver};
 #[cfg(feature = "serde")]
-pub use serde::ensure_unqiue;
+#[allow(deprecated)]
+pub use serde::{ensure_unique, ensure_unqiue};
 pub use share_map::{DuplicateKeyError, ShareMap};
+pub use view::ShareMapView;
@@ -0,0 +1,180 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter};
+
+use rkyv::munge::munge;
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Place, Portable, Serialize};
+
+use crate::{MapIteration, ShareMap};
+
+/// The archived form of a [`ShareMap`], supporting lookups directly on the archived bytes.
+///
+/// Keys are archived in sorted order, so lookups are a binary search, and iteration is in key
+/// order regardless of the original `Map` implementation. This relies on the archived keys
+/// ordering the same way as the original keys, so only [`ArchiveOrd`] keys can be archived.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::ShareMap;
+///
+/// let map = ShareMap::<String, u32>::try_from_iter([("key2".into(), 100), ("key1".into(), 42)])?;
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&map)?;
+///
+/// let archived = rkyv::access::<rkyv::Archived<ShareMap<String, u32>>, rkyv::rancor::Error>(&bytes)?;
+/// assert_eq!(archived.len(), 2);
+/// assert_eq!(archived.get("key1").map(|value| value.to_native()), Some(42));
+/// assert_eq!(archived.get("key3"), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Portable, rkyv::bytecheck::CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedShareMap<K, V> {
+    keys: ArchivedVec<K>,
+    values: ArchivedVec<V>,
+}
+
+impl<K, V> ArchivedShareMap<K, V> {
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        // the keys were sorted before archiving, and ArchiveOrd keys keep that order archived
+        self.keys
+            .binary_search_by(|archived| archived.borrow().cmp(key))
+            .ok()
+    }
+
+    /// Returns the archived value associated with the given key, if it exists.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.index_of(key).map(|index| &self.values[index])
+    }
+
+    /// Returns the archived key and value associated with the given key, if it exists.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.index_of(key)
+            .map(|index| (&self.keys[index], &self.values[index]))
+    }
+
+    /// Checks if the archived map contains a specific key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.index_of(key).is_some()
+    }
+
+    /// Returns an iterator over the archived key-value pairs, in key order.
+    pub fn iter(&self) -> std::iter::Zip<std::slice::Iter<'_, K>, std::slice::Iter<'_, V>> {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    /// Returns the number of key-value pairs in the archived map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Checks if the archived map is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a ArchivedShareMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::iter::Zip<std::slice::Iter<'a, K>, std::slice::Iter<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for ArchivedShareMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Key types whose archived form orders the same way as the key itself, so an
+/// [`ArchivedShareMap`] can binary search the keys in the order they were sorted in before
+/// archiving.
+///
+/// This trait is sealed. It is implemented for [`String`], [`bool`], [`char`] and the fixed-size
+/// integer types.
+pub trait ArchiveOrd: Archive<Archived: Ord> + Ord + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_archive_ord {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl ArchiveOrd for $ty {}
+        )*
+    };
+}
+
+impl_archive_ord!(
+    String, bool, char, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128
+);
+
+/// The resolver for an [`ArchivedShareMap`].
+pub struct ShareMapResolver {
+    keys: VecResolver,
+    values: VecResolver,
+}
+
+impl<K: Archive, V: Archive, Map> Archive for ShareMap<K, V, Map> {
+    type Archived = ArchivedShareMap<K::Archived, V::Archived>;
+    type Resolver = ShareMapResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedShareMap { keys, values } = out);
+        ArchivedVec::resolve_from_len(self.len(), resolver.keys, keys);
+        ArchivedVec::resolve_from_len(self.len(), resolver.values, values);
+    }
+}
+
+/// Archives the entries sorted by key, see [`ArchivedShareMap`].
+impl<K, V, Map, S> Serialize<S> for ShareMap<K, V, Map>
+where
+    K: Serialize<S> + ArchiveOrd,
+    V: Serialize<S>,
+    Map: MapIteration<K, usize>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+
+        let keys = ArchivedVec::<K::Archived>::serialize_from_iter::<K, _, _>(
+            entries.iter().map(|(key, _)| *key),
+            serializer,
+        )?;
+        let values = ArchivedVec::<V::Archived>::serialize_from_iter::<V, _, _>(
+            entries.iter().map(|(_, value)| *value),
+            serializer,
+        )?;
+
+        Ok(ShareMapResolver { keys, values })
+    }
+}
@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::serde::as_pairs;
+use crate::{ShareMap, ShareMapView};
+
+/// A [`ShareMap`] has the schema of an object with values of `V`'s schema, matching its
+/// [`Serialize`](::serde::Serialize) implementation, if `K` can be written as an object key.
+/// JSON object keys are strings, and `serde_json` writes strings, integers, floats and booleans
+/// as string keys, so `K`'s schema must have one of those types.
+///
+/// Other key types, such as tuples or structs, cannot be written as object keys and must be
+/// serialized as an array of pairs with [`as_pairs`](crate::serde::as_pairs), so for those the
+/// schema is that of [`as_pairs::json_schema`](crate::serde::as_pairs::json_schema).
+///
+/// For fields using [`as_pairs`](crate::serde::as_pairs) or
+/// [`ensure_unique`](crate::ensure_unique), use the `json_schema` function of that module with
+/// `#[schemars(schema_with = "...")]` instead.
+///
+/// # Example
+///
+/// ```rust
+/// use share_map::ShareMap;
+///
+/// #[derive(schemars::JsonSchema)]
+/// struct Config {
+///     limits: ShareMap<String, u32>,
+/// }
+///
+/// let schema = schemars::schema_for!(Config);
+/// assert_eq!(schema.get("properties").and_then(|properties| properties.get("limits")), Some(&serde_json::json!({
+///     "type": "object",
+///     "additionalProperties": { "type": "integer", "format": "uint32", "minimum": 0 },
+/// })));
+/// ```
+impl<K: JsonSchema, V: JsonSchema, Map> JsonSchema for ShareMap<K, V, Map> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("ShareMap_of_{}_and_{}", K::schema_name(), V::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "share_map::ShareMap<{}, {}>",
+            K::schema_id(),
+            V::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        match is_object_key::<K>(generator) {
+            true => json_schema!({
+                "type": "object",
+                "additionalProperties": generator.subschema_for::<V>(),
+            }),
+            false => as_pairs::json_schema::<K, V>(generator),
+        }
+    }
+}
+
+/// Checks if `serde_json` can write `K` as an object key, judging by the type of its schema.
+fn is_object_key<K: JsonSchema>(generator: &SchemaGenerator) -> bool {
+    // generate into a copy, so that definitions only needed for the check are not kept
+    let schema = K::json_schema(&mut generator.clone());
+
+    matches!(
+        schema.get("type").and_then(|kind| kind.as_str()),
+        Some("string" | "integer" | "number" | "boolean")
+    )
+}
+
+/// A [`ShareMapView`] has the same schema as the equivalent [`ShareMap`].
+impl<K: JsonSchema, V: JsonSchema, Map> JsonSchema for ShareMapView<K, V, Map> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        ShareMap::<K, V>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        ShareMap::<K, V>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        ShareMap::<K, V>::json_schema(generator)
+    }
+}
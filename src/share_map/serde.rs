@@ -1,18 +1,20 @@
-use std::collections::HashMap;
-
-use crate::{Len, MapIteration, ShareMap};
+use crate::serde::{DuplicatePolicy, ShareMapVisitor};
+use crate::{MapIteration, ShareMap, ShareMapView, StreamingMap};
 
+/// Entries are deserialized directly into the [`ShareMap`]. If a key is duplicated, the value of
+/// its last occurrence is kept, see [`DuplicatePolicy::KeepLast`]. To reject duplicates, use
+/// [`ensure_unique`].
 impl<'de, K, V, Map> serde::Deserialize<'de> for ShareMap<K, V, Map>
 where
-    K: Eq + std::hash::Hash + serde::Deserialize<'de>,
+    K: serde::Deserialize<'de>,
     V: serde::Deserialize<'de>,
-    Map: FromIterator<(K, usize)> + Len,
+    Map: StreamingMap<K, usize>,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        HashMap::deserialize(deserializer).map(ShareMap::from)
+        deserializer.deserialize_map(ShareMapVisitor::new(DuplicatePolicy::KeepLast))
     }
 }
 
@@ -30,19 +32,71 @@ where
     }
 }
 
+impl<K, V, Map> ShareMap<K, V, Map> {
+    /// Serializes the map with its entries sorted by key, regardless of the `Map` implementation.
+    ///
+    /// This gives deterministic output for backends with unspecified iteration order, such as
+    /// [`HashMap`](std::collections::HashMap). See also [`crate::serde::sorted`].
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the serializer are passed through.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("b", 2), ("c", 3), ("a", 1)])?;
+    ///
+    /// let json = map.serialize_sorted(serde_json::value::Serializer)?;
+    /// assert_eq!(json.to_string(), r#"{"a":1,"b":2,"c":3}"#);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn serialize_sorted<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        K: serde::Serialize + Ord,
+        V: serde::Serialize,
+        Map: MapIteration<K, usize>,
+    {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+
+        serializer.collect_map(entries)
+    }
+}
+
+/// Serializes the view as a map of its entries, the same as the equivalent [`ShareMap`].
+impl<K, V, Map> serde::Serialize for ShareMapView<K, V, Map>
+where
+    K: serde::Serialize,
+    V: serde::Serialize,
+    Map: MapIteration<K, usize>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
 /// Provides deserialization of a [`ShareMap`] that enforces that all keys are unique.
 ///
-/// You can use this by annotating the type with `#[serde(with = "ensure_unqiue")]` or
-/// by calling the [`ensure_unqiue::deserialize`] function directly.
+/// You can use this by annotating the type with `#[serde(with = "ensure_unique")]` or
+/// by calling the [`ensure_unique::deserialize`] function directly.
 ///
 /// # Example
 ///
 /// ```rust
-/// use share_map::{ShareMap, ensure_unqiue};
+/// use share_map::{ShareMap, ensure_unique};
 ///
 /// #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 /// struct TestContainer {
-///     #[serde(with = "ensure_unqiue")]
+///     #[serde(with = "ensure_unique")]
 ///     map: ShareMap<String, u8>,
 /// }
 ///
@@ -64,13 +118,11 @@ where
 /// # Ok(())
 /// # }
 /// ```
-pub mod ensure_unqiue {
-    use std::{hash::Hash, marker::PhantomData};
-
+pub mod ensure_unique {
     use serde::Serialize;
-    use tap::Pipe;
 
-    use crate::{Len, ShareMap};
+    use crate::serde::{DuplicatePolicy, ShareMapVisitor};
+    use crate::{ShareMap, StreamingMap};
 
     /// Serializes the map. This method simply passes through to [`ShareMap::serialize`].
     ///
@@ -97,39 +149,43 @@ pub mod ensure_unqiue {
     pub fn deserialize<'de, D, K, V, Map>(deserializer: D) -> Result<ShareMap<K, V, Map>, D::Error>
     where
         D: serde::Deserializer<'de>,
-        K: Eq + Hash + serde::Deserialize<'de>,
+        K: serde::Deserialize<'de>,
         V: serde::Deserialize<'de>,
-        Map: FromIterator<(K, usize)> + Len,
+        Map: StreamingMap<K, usize>,
     {
-        deserializer.deserialize_map(ShareMapVisitor(PhantomData))
+        deserializer.deserialize_map(ShareMapVisitor::new(DuplicatePolicy::Reject))
     }
 
-    #[derive(Debug)]
-    struct ShareMapVisitor<K, V, Map>(PhantomData<ShareMap<K, V, Map>>);
-
-    impl<'de, K, V, Map> serde::de::Visitor<'de> for ShareMapVisitor<K, V, Map>
-    where
-        K: Eq + Hash + serde::Deserialize<'de>,
-        V: serde::Deserialize<'de>,
-        Map: FromIterator<(K, usize)> + Len,
-    {
-        type Value = ShareMap<K, V, Map>;
-
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("a map with unique keys")
-        }
-
-        fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
-        where
-            M: serde::de::MapAccess<'de>,
-        {
-            let mut entries = access.size_hint().unwrap_or(0).pipe(Vec::with_capacity);
-
-            while let Some(entry) = access.next_entry()? {
-                entries.push(entry);
-            }
-
-            ShareMap::try_from_iter(entries).map_err(serde::de::Error::custom)
-        }
+    /// Generates the schema of a [`ShareMap`] with values of type `V`, annotated with
+    /// `"uniqueKeys": true`. Use this with `#[schemars(schema_with = "...")]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use share_map::{ShareMap, ensure_unique};
+    ///
+    /// #[derive(schemars::JsonSchema)]
+    /// struct Config {
+    ///     #[schemars(schema_with = "ensure_unique::json_schema::<u32>")]
+    ///     limits: ShareMap<String, u32>,
+    /// }
+    ///
+    /// let schema = schemars::schema_for!(Config);
+    /// let limits = schema.get("properties").and_then(|properties| properties.get("limits"));
+    /// assert_eq!(limits.and_then(|limits| limits.get("uniqueKeys")), Some(&serde_json::json!(true)));
+    /// ```
+    #[cfg(feature = "schemars")]
+    pub fn json_schema<V: schemars::JsonSchema>(
+        generator: &mut schemars::SchemaGenerator,
+    ) -> schemars::Schema {
+        let mut schema = <ShareMap<String, V> as schemars::JsonSchema>::json_schema(generator);
+        schema.insert("uniqueKeys".into(), true.into());
+        schema
     }
 }
+
+/// The previous, misspelled name of [`ensure_unique`].
+#[deprecated(note = "renamed to `ensure_unique`")]
+pub mod ensure_unqiue {
+    pub use super::ensure_unique::*;
+}
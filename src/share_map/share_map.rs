@@ -1,7 +1,8 @@
+use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
-use std::ops::Index;
+use std::ops::{Bound, Index, RangeBounds};
 use std::sync::Arc;
 
 #[cfg(doc)]
@@ -10,8 +11,11 @@ use collect_failable::TryFromIterator;
 use fluent_result::into::IntoResult;
 use frozen_collections::{Len, MapIteration, MapQuery};
 
-use crate::Handle;
-use crate::Iter;
+use crate::handle::{Store, try_unwrap_store};
+use crate::{
+    BatchMapQuery, Handle, HandleBatch, Handles, InsertionOrderIter, IntoIter, Iter,
+    KeyValueMapQuery, Keys, OrderedMapQuery, ShareMapView, SliceHandle, Values,
+};
 
 /// An immutable map's of values that supports shared read access and provides access to stable,
 /// sharable value references ([`Handle`]s).
@@ -31,7 +35,8 @@ use crate::Iter;
 ///
 /// # Map Iteration
 ///
-/// Because ownership of values is shared, owned enumeration including values is not provided.
+/// Because ownership of values is shared, owned enumeration yields a [`Handle`] to each value
+/// instead of the value itself. See [`ShareMap::handles`] and [`IntoIter`].
 ///
 /// # Type Parameters
 /// - `K`: The key type stored in the map
@@ -54,7 +59,7 @@ use crate::Iter;
 #[derive(Clone)]
 pub struct ShareMap<K, V, Map = HashMap<K, usize>> {
     index_map: Map,
-    values: Arc<[V]>,
+    values: Store<V>,
     _marker: std::marker::PhantomData<K>,
 }
 
@@ -64,7 +69,7 @@ pub struct ShareMap<K, V, Map = HashMap<K, usize>> {
 pub struct DuplicateKeyError;
 
 impl<K, V, Map> ShareMap<K, V, Map> {
-    fn new(index_map: Map, values: Arc<[V]>) -> Self {
+    pub(crate) fn new(index_map: Map, values: Store<V>) -> Self {
         Self {
             index_map,
             values,
@@ -115,6 +120,80 @@ impl<K, V, Map> ShareMap<K, V, Map> {
         }
     }
 
+    /// Creates a new [`ShareMap`] from `(key, index)` pairs into `values`, which must all be
+    /// [`Some`].
+    ///
+    /// If `Map` drops duplicate keys, the values it no longer indexes are dropped too, and the
+    /// surviving values keep their relative order in `values`.
+    pub(crate) fn from_indexed(
+        mut key_index_pairs: Vec<(K, usize)>,
+        mut values: Vec<Option<V>>,
+    ) -> Self
+    where
+        Map: FromIterator<(K, usize)> + Len + MapIteration<K, usize>,
+    {
+        // converting the key_index_pairs into a map should remove duplicates
+        let index_map: Map = key_index_pairs.drain(..).collect();
+
+        match usize::cmp(&index_map.len(), &values.len()) {
+            Ordering::Equal => {
+                // PANIC SAFETY: all values in store are Some
+                let store = values.into_iter().map(Option::unwrap).collect();
+                Self::new(index_map, store)
+            }
+            Ordering::Greater => panic!("Invalid map implementation"),
+            Ordering::Less => {
+                // in the event of duplicates, rebuild the index_map and store
+                let index_map_len = index_map.len();
+
+                // keep the surviving values in the order they were provided
+                let mut entries: Vec<_> = index_map.into_iter().collect();
+                entries.sort_unstable_by_key(|(_, old_index)| *old_index);
+
+                let (key_index_pairs, values) = entries
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (key, old_index))| {
+                        // PANIC SAFETY: all values in store are Some
+                        ((key, index), values[old_index].take().unwrap())
+                    })
+                    // fold is used instead of zip to reuse key_index_pairs
+                    .fold(
+                        (key_index_pairs, Vec::with_capacity(index_map_len)),
+                        |(mut key_index_pairs, mut new_values), (key_index_pair, value)| {
+                            new_values.push(value);
+                            key_index_pairs.push(key_index_pair);
+                            (key_index_pairs, new_values)
+                        },
+                    );
+
+                let index_map: Map = Map::from_iter(key_index_pairs);
+
+                assert!(
+                    index_map.len() == values.len() && values.len() == index_map_len,
+                    "Invalid map implementation"
+                );
+
+                Self::new(index_map, values.into())
+            }
+        }
+    }
+
+    /// Creates a new [`ShareMap`] from entries that are known to have unique keys.
+    fn from_unique_entries<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        Map: FromIterator<(K, usize)>,
+    {
+        let (values, key_index_pairs): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, (key, value))| (value, (key, index)))
+            .unzip();
+
+        Self::new(Map::from_iter(key_index_pairs), values.into())
+    }
+
     /// Returns the value associated with the given key, if it exists.
     ///
     /// # Examples
@@ -123,56 +202,948 @@ impl<K, V, Map> ShareMap<K, V, Map> {
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use share_map::ShareMap;
     ///
-    /// let map = ShareMap::<&str, i32>::try_from_iter([("key1", 42)])?;
-    /// let value: Option<&i32> = map.get("key1");
+    /// let map = ShareMap::<&str, i32>::try_from_iter([("key1", 42)])?;
+    /// let value: Option<&i32> = map.get("key1");
+    ///
+    /// assert_eq!(value, Some(&42));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map.get(key).map(|index| &self.values[*index])
+    }
+
+    /// Returns the value associated with the given key as a [`Handle`], if it exists.
+    ///
+    /// The returned [`Handle`] will never invalidate, even if the original [`ShareMap`] is
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, Handle};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+    ///
+    /// let handle: Handle<i32> = map.get_handle("key1").ok_or("Key not found")?;
+    ///
+    /// assert_eq!(*handle, 42);
+    ///
+    /// // handle is still valid after map is dropped
+    /// drop(map);
+    ///
+    /// assert_eq!(*handle, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_handle<Q: ?Sized>(&self, key: &Q) -> Option<Handle<V>>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map
+            .get(key)
+            .map(|index| Handle::new(self.values.clone(), *index))
+    }
+
+    /// Returns the stored key and the value associated with the given key, if it exists.
+    ///
+    /// This is useful when looking up with a borrowed or normalized form of the key, to get back
+    /// the canonical key stored in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::sync::Arc;
+    /// use share_map::ShareMap;
+    ///
+    /// let key: Arc<str> = Arc::from("key1");
+    /// let map = ShareMap::<_, _>::try_from_iter([(key.clone(), 42)])?;
+    ///
+    /// let (stored, value) = map.get_key_value("key1").ok_or("Key not found")?;
+    /// assert!(Arc::ptr_eq(stored, &key));
+    /// assert_eq!(*value, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_key_value<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Map: KeyValueMapQuery<K, Q, usize>,
+    {
+        self.index_map
+            .get_key_value(key)
+            .map(|(key, index)| (key, &self.values[*index]))
+    }
+
+    /// Returns the stored key and the value associated with the given key as a [`Handle`], if it
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{Handle, ShareMap};
+    ///
+    /// let map = ShareMap::<String, _>::try_from_iter([("key1".to_string(), 42)])?;
+    ///
+    /// let (key, handle): (&String, Handle<i32>) = map.get_key_handle("key1").ok_or("Key not found")?;
+    /// assert_eq!(key, "key1");
+    /// assert_eq!(*handle, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_key_handle<Q: ?Sized>(&self, key: &Q) -> Option<(&K, Handle<V>)>
+    where
+        Map: KeyValueMapQuery<K, Q, usize>,
+    {
+        self.index_map
+            .get_key_value(key)
+            .map(|(key, index)| (key, Handle::new(self.values.clone(), *index)))
+    }
+
+    /// Returns the values associated with each of `keys`, in the same order.
+    ///
+    /// The lookups are delegated to [`BatchMapQuery::get_many`], so `Map` types that can probe
+    /// several keys at once may do so.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2), ("key3", 3)])?;
+    ///
+    /// let [one, three, missing] = map.get_many(["key1", "key3", "key4"]);
+    /// assert_eq!(one, Some(&1));
+    /// assert_eq!(three, Some(&3));
+    /// assert_eq!(missing, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_many<Q: ?Sized, const N: usize>(&self, keys: [&Q; N]) -> [Option<&V>; N]
+    where
+        Map: BatchMapQuery<Q, usize>,
+    {
+        self.index_map
+            .get_many(keys)
+            .map(|index| index.map(|index| &self.values[*index]))
+    }
+
+    /// Returns an iterator over the values associated with each of `keys`, in the same order.
+    ///
+    /// This is the unsized form of [`ShareMap::get_many`], for when the number of keys is not
+    /// known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2), ("key3", 3)])?;
+    /// let keys = vec!["key1", "key4", "key2"];
+    ///
+    /// let values: Vec<_> = map.get_many_iter(keys.iter().copied()).collect();
+    /// assert_eq!(values, [Some(&1), None, Some(&2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_many_iter<'a, Q, I>(&'a self, keys: I) -> impl Iterator<Item = Option<&'a V>>
+    where
+        Q: ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+        Map: MapQuery<Q, usize>,
+    {
+        keys.into_iter().map(|key| self.get(key))
+    }
+
+    /// Returns the values associated with each of `keys` as a [`HandleBatch`], in the same order.
+    ///
+    /// All keys are resolved before the batch is created, and the whole batch shares a single
+    /// reference to the value store, so this costs one atomic increment however many keys are
+    /// found. See [`HandleBatch::into_handles`] to take individual [`Handle`]s out of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    ///
+    /// let batch = map.get_many_handles(["key1", "key3"]);
+    /// drop(map);
+    ///
+    /// assert_eq!(batch.values(), [Some(&1), None]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_many_handles<Q: ?Sized, const N: usize>(&self, keys: [&Q; N]) -> HandleBatch<V, N>
+    where
+        Map: BatchMapQuery<Q, usize>,
+    {
+        let indices = self.index_map.get_many(keys).map(Option::<&usize>::copied);
+        HandleBatch::new(self.values.clone(), indices)
+    }
+
+    /// Returns the run of values stored from the value of `first` through the value of `last`
+    /// (inclusive) as a [`SliceHandle`], if both keys exist.
+    ///
+    /// Values are stored in the order they were provided on construction, so this can be used to
+    /// reference a group of values that were inserted consecutively.
+    ///
+    /// Returns [`None`] if either key does not exist, or if the value of `last` is stored before
+    /// the value of `first`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{ShareMap, SliceHandle};
+    ///
+    /// let data = [("shard1", 1), ("shard2", 2), ("shard3", 3), ("other", 4)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let shards: SliceHandle<i32> = map.get_slice_handle("shard1", "shard3").ok_or("Key not found")?;
+    /// assert_eq!(*shards, [1, 2, 3]);
+    ///
+    /// // last is stored before first
+    /// assert!(map.get_slice_handle("other", "shard1").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_slice_handle<Q: ?Sized>(&self, first: &Q, last: &Q) -> Option<SliceHandle<V>>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        let first = *self.index_map.get(first)?;
+        let last = *self.index_map.get(last)?;
+
+        match first <= last {
+            true => Some(SliceHandle::new(self.values.clone(), first..last + 1)),
+            false => None,
+        }
+    }
+
+    /// Checks if the map contains a specific key.
+    ///
+    /// Key equality is determined by the `Map` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+    ///
+    /// assert_eq!(map.contains_key("key1"), true);
+    /// assert_eq!(map.contains_key("key3"), false);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map.contains_key(key)
+    }
+
+    /// Checks if the [`Handle`] points into this map's value store.
+    ///
+    /// This is `true` for [`Handle`]s obtained from this map or one of its clones, and `false`
+    /// for [`Handle`]s from any other map, even if it holds equal values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map1 = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+    /// let map2 = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+    /// let handle = map1.get_handle("key1").ok_or("Key not found")?;
+    ///
+    /// assert!(map1.owns(&handle));
+    /// assert!(!map2.owns(&handle));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn owns(&self, handle: &Handle<V>) -> bool {
+        Arc::ptr_eq(self.values.values(), Handle::store(handle))
+    }
+
+    /// Returns the key associated with the value referenced by the [`Handle`], if the
+    /// [`Handle`] points into this map's value store.
+    ///
+    /// This is a reverse lookup, and is `O(n)` in the number of entries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map1 = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)])?;
+    /// let map2 = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)])?;
+    /// let handle = map1.get_handle("key2").ok_or("Key not found")?;
+    ///
+    /// assert_eq!(map1.key_of(&handle), Some(&"key2"));
+    /// assert_eq!(map2.key_of(&handle), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn key_of(&self, handle: &Handle<V>) -> Option<&K>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        if !self.owns(handle) {
+            return None;
+        }
+
+        let index = Handle::index(handle);
+        self.index_map
+            .iter()
+            .find_map(|(key, key_index)| (*key_index == index).then_some(key))
+    }
+
+    /// Returns an iterator over the key-value pairs in the map.
+    ///
+    /// Order of iteration is dependent on the `Map` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// // BTreeMap gurantees iteration order
+    /// let data = [("key1", 42), ("key2", 100)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    ///
+    /// let map_keys: Vec<_> = map.iter().collect();
+    /// let data_keys: Vec<_> = data.iter().map(|(k, v)| (k, v)).collect();
+    ///
+    /// assert_eq!(map_keys, data_keys);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V, Map::Iterator<'_>>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        Iter::new(self.index_map.iter(), &self.values)
+    }
+
+    /// Creates a [`ShareMapView`] over the entries for which `predicate` returns `true`.
+    ///
+    /// The view shares this map's value store, so no values are copied, and [`Handle`]s obtained
+    /// through it are [`Handle::ref_eq`] to [`Handle`]s obtained from this map. Only the matching
+    /// keys are cloned into the view's own index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("tenant1/a", 1), ("tenant2/a", 2), ("tenant1/b", 3)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let tenant1 = map.view(|key, _| key.starts_with("tenant1/"));
+    /// assert_eq!(tenant1.len(), 2);
+    /// assert_eq!(tenant1.get("tenant1/b"), Some(&3));
+    /// assert_eq!(tenant1.get("tenant2/a"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn view<F>(&self, mut predicate: F) -> ShareMapView<K, V, Map>
+    where
+        K: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        F: FnMut(&K, &V) -> bool,
+    {
+        let index_map = self
+            .index_map
+            .iter()
+            .filter(|(key, index)| predicate(*key, &self.values[**index]))
+            .map(|(key, index)| (key.clone(), *index))
+            .collect();
+
+        ShareMapView::new(index_map, self.values.clone())
+    }
+
+    /// Returns an iterator over the key-value pairs in the map, in insertion order.
+    ///
+    /// Unlike [`ShareMap::iter`], the order of iteration does not depend on the `Map`
+    /// implementation: entries are yielded in the order they were provided on construction.
+    /// Creating the iterator is `O(n)`, as it collects the keys in store order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("zeta", 1), ("alpha", 2), ("mu", 3)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let pairs: Vec<_> = map.iter_insertion_order().collect();
+    /// assert_eq!(pairs, [(&"zeta", &1), (&"alpha", &2), (&"mu", &3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_insertion_order(&self) -> InsertionOrderIter<'_, K, V>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        let mut keys = vec![None; self.values.len()];
+        for (key, index) in self.index_map.iter() {
+            keys[*index] = Some(key);
+        }
+
+        // PANIC SAFETY: every value in the store has exactly one key
+        let keys = keys.into_iter().map(Option::unwrap).collect();
+        InsertionOrderIter::new(keys, &self.values)
+    }
+
+    /// Returns an iterator over the key-value pairs in the map, yielding a [`Handle`] to each
+    /// value.
+    ///
+    /// Order of iteration is dependent on the `Map` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::{Handle, ShareMap};
+    ///
+    /// let data = [("key1", 42), ("key2", 100)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    ///
+    /// let handles: Vec<(&&str, Handle<i32>)> = map.handles().collect();
+    ///
+    /// // handles are still valid after map is dropped
+    /// let values: Vec<_> = handles.into_iter().map(|(_, handle)| handle).collect();
+    /// drop(map);
+    ///
+    /// assert_eq!(*values[0], 42);
+    /// assert_eq!(*values[1], 100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn handles(&self) -> Handles<'_, K, V, Map::Iterator<'_>>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        Handles::new(self.index_map.iter(), &self.values)
+    }
+
+    /// Returns an iterator over the keys in the map.
+    ///
+    /// Order of iteration is dependent on the `Map` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// // BTreeMap gurantees iteration order
+    /// let data = [("key1", 42), ("key2", 100)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    ///
+    /// let map_keys: Vec<_> = map.keys().collect();
+    /// let data_keys: Vec<_> = data.iter().map(|(k, _)| k).collect();
+    ///
+    /// assert_eq!(map_keys, data_keys);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, Map::Iterator<'_>>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        Keys::new(self.index_map.iter())
+    }
+
+    /// Returns an iterator over the values in the map.
+    ///
+    /// Unlike [`HashMap::values`], this method is `O(n:len)`, not `O(n:capacity)`.
+    ///
+    /// Values are yielded in insertion order, that is, the order they were provided on
+    /// construction, regardless of the `Map` implementation. The value of a duplicated key keeps
+    /// the position it was provided at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("key2", 100), ("key1", 42)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let map_values: Vec<_> = map.values().collect();
+    /// assert_eq!(map_values, [&100, &42]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn values(&self) -> Values<'_, V> {
+        Values::new(&self.values)
+    }
+
+    /// Consumes the [`ShareMap`] and returns a key (`K`) iterator.
+    ///
+    /// Order of iteration is dependent on the `Map` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// // BTreeMap gurantees iteration order
+    /// let data = [("key1", 42), ("key2", 100)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    ///
+    /// let map_keys: Vec<_> = map.into_keys().collect();
+    /// let data_keys: Vec<_> = data.into_iter().map(|(k, _)| k).collect();
+    ///
+    /// assert_eq!(map_keys, data_keys);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_keys(self) -> Map::IntoKeyIterator
+    where
+        Map: MapIteration<K, usize>,
+    {
+        self.index_map.into_keys()
+    }
+
+    /// Consumes the [`ShareMap`] and returns the value store.
+    ///
+    /// The values are in insertion order, that is, the order they were provided on construction.
+    /// The value of a duplicated key keeps the position it was provided at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::sync::Arc;
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("key2", 100), ("key1", 42)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let map_values: Arc<[i32]> = map.into_values();
+    /// assert_eq!(*map_values, [100, 42]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_values(self) -> Arc<[V]> {
+        self.values.into_values()
+    }
+
+    /// Attempts to consume the [`ShareMap`] and return its key-value pairs, moving the values out
+    /// of the value store.
+    ///
+    /// This succeeds only if the map holds the only reference to its value store, that is, no
+    /// [`Handle`]s into it or clones of the map are alive.
+    ///
+    /// Order of the entries is dependent on the `Map` implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ShareMap`] unchanged if the value store is still shared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("key1", String::from("a")), ("key2", String::from("b"))];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data.clone())?;
+    ///
+    /// // an outstanding handle shares the store
+    /// let handle = map.get_handle("key1").ok_or("Key not found")?;
+    /// let map = map.try_into_entries().expect_err("store is shared");
+    ///
+    /// drop(handle);
+    /// let entries = map.try_into_entries().map_err(|_| "store is shared")?;
+    /// assert_eq!(entries, data);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_entries(self) -> Result<Vec<(K, V)>, Self>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        let Self {
+            index_map, values, ..
+        } = self;
+
+        match try_unwrap_store(values) {
+            Ok(values) => {
+                let mut values: Vec<_> = values.into_iter().map(Some).collect();
+
+                // each index in the map is unique and in bounds, so no entry is skipped
+                index_map
+                    .into_iter()
+                    .filter_map(|(key, index)| {
+                        values
+                            .get_mut(index)
+                            .and_then(Option::take)
+                            .map(|value| (key, value))
+                    })
+                    .collect::<Vec<_>>()
+                    .into_ok()
+            }
+            Err(values) => Err(Self::new(index_map, values)),
+        }
+    }
+
+    /// Consumes the [`ShareMap`] and returns its key-value pairs, moving the values out if the
+    /// value store is not shared, and cloning them otherwise.
+    fn into_owned_entries(self) -> Vec<(K, V)>
+    where
+        V: Clone,
+        Map: MapIteration<K, usize>,
+    {
+        self.try_into_entries().unwrap_or_else(|map| {
+            map.into_iter()
+                .map(|(key, handle)| (key, V::clone(&handle)))
+                .collect()
+        })
+    }
+
+    /// Consumes the [`ShareMap`] and returns the key-value pairs for which `predicate` returns
+    /// `true` in insertion order, moving the values out if the value store is not shared, and
+    /// cloning only the retained values otherwise.
+    fn into_ordered_entries_where<F>(self, mut predicate: F) -> Vec<(K, V)>
+    where
+        V: Clone,
+        Map: MapIteration<K, usize>,
+        F: FnMut(&K, &V) -> bool,
+    {
+        let Self {
+            index_map, values, ..
+        } = self;
+
+        // indices are exactly 0..len, so once sorted the keys line up with the value store
+        let mut keys: Vec<_> = index_map.into_iter().collect();
+        keys.sort_unstable_by_key(|(_, index)| *index);
+        let keys = keys.into_iter().map(|(key, _)| key);
+
+        match try_unwrap_store(values) {
+            Ok(values) => keys
+                .zip(values)
+                .filter(|(key, value)| predicate(key, value))
+                .collect(),
+            Err(values) => keys
+                .zip(values.iter())
+                .filter(|(key, value)| predicate(key, value))
+                .map(|(key, value)| (key, value.clone()))
+                .collect(),
+        }
+    }
+
+    /// Consumes the [`ShareMap`] and returns a new [`ShareMap`] containing only the entries for
+    /// which `predicate` returns `true`.
+    ///
+    /// Retained values are moved into the new map if the value store is not shared, that is, no
+    /// [`Handle`]s into it or clones of the map are alive. Otherwise they are cloned. Insertion
+    /// order is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("feature1", true), ("feature2", false), ("feature3", true)];
+    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
+    ///
+    /// let enabled = map.filter(|_, enabled| *enabled);
+    /// assert_eq!(enabled.len(), 2);
+    /// assert!(!enabled.contains_key("feature2"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn filter<F>(self, predicate: F) -> Self
+    where
+        V: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        F: FnMut(&K, &V) -> bool,
+    {
+        Self::from_unique_entries(self.into_ordered_entries_where(predicate))
+    }
+
+    /// Consumes the [`ShareMap`] and splits it in two: the entries for which `predicate` returns
+    /// `true`, and those for which it returns `false`.
+    ///
+    /// Values are moved into the new maps if the value store is not shared, and cloned otherwise.
+    /// Insertion order is preserved in both maps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2), ("c", 3), ("d", 4)])?;
+    ///
+    /// let (even, odd) = map.partition(|_, value| value % 2 == 0);
+    /// assert_eq!(even.values().collect::<Vec<_>>(), [&2, &4]);
+    /// assert_eq!(odd.values().collect::<Vec<_>>(), [&1, &3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition<F>(self, mut predicate: F) -> (Self, Self)
+    where
+        V: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        F: FnMut(&K, &V) -> bool,
+    {
+        let (matching, rest): (Vec<_>, Vec<_>) = self
+            .into_ordered_entries_where(|_, _| true)
+            .into_iter()
+            .partition(|(key, value)| predicate(key, value));
+
+        (
+            Self::from_unique_entries(matching),
+            Self::from_unique_entries(rest),
+        )
+    }
+
+    /// Creates a new [`ShareMap`] with the same keys, transforming each value with `f`.
+    ///
+    /// The key index is cloned rather than rebuilt, so keys are not re-hashed or re-checked for
+    /// duplicates. `f` is called once per entry, in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("one", 1), ("two", 2)])?;
+    ///
+    /// let labels: ShareMap<_, String> = map.map_values(|key, value| format!("{key}={value}"));
+    /// assert_eq!(labels.get("two").map(String::as_str), Some("two=2"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_values<U, F>(&self, mut f: F) -> ShareMap<K, U, Map>
+    where
+        Map: MapIteration<K, usize> + Clone,
+        F: FnMut(&K, &V) -> U,
+    {
+        let values: Vec<_> = self
+            .iter_insertion_order()
+            .map(|(key, value)| f(key, value))
+            .collect();
+
+        ShareMap::new(self.index_map.clone(), values.into())
+    }
+
+    /// Attempts to create a new [`ShareMap`] with the same keys, transforming each value with
+    /// `f`.
+    ///
+    /// The key index is cloned rather than rebuilt, so keys are not re-hashed or re-checked for
+    /// duplicates. `f` is called once per entry, in insertion order, stopping at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the key of the first entry for which `f` fails, along with its error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("port", "8080"), ("timeout", "30")])?;
+    ///
+    /// let parsed: ShareMap<_, u16> = map
+    ///     .try_map_values(|_, value| value.parse())
+    ///     .map_err(|(key, err)| format!("{key}: {err}"))?;
+    /// assert_eq!(parsed.get("port"), Some(&8080));
+    ///
+    /// let bad = ShareMap::<_, _>::try_from_iter([("port", "8080"), ("timeout", "soon")])?;
+    /// let (key, _) = bad.try_map_values(|_, value| value.parse::<u16>()).expect_err("should fail");
+    /// assert_eq!(key, "timeout");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_map_values<U, E, F>(&self, mut f: F) -> Result<ShareMap<K, U, Map>, (K, E)>
+    where
+        K: Clone,
+        Map: MapIteration<K, usize> + Clone,
+        F: FnMut(&K, &V) -> Result<U, E>,
+    {
+        let values = self
+            .iter_insertion_order()
+            .map(|(key, value)| f(key, value).map_err(|err| (key.clone(), err)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ShareMap::new(self.index_map.clone(), values.into()).into_ok()
+    }
+
+    /// Creates a new [`ShareMap`] containing the keys of both maps.
+    ///
+    /// Values for keys present in both maps are combined with `merge`, which is passed the key,
+    /// the value from this map and the value from `other`. Entries from this map come first in
+    /// the result's insertion order, followed by the entries only in `other`. `other` may use a
+    /// different `Map` type.
+    ///
+    /// The result has its own value store, so the values are cloned, and [`Handle`]s obtained
+    /// from it are not [`Handle::ref_eq`] to [`Handle`]s obtained from either map. Only if one of
+    /// the maps is empty does the result share the other map's value store instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let defaults = ShareMap::<_, _>::try_from_iter([("port", 80), ("timeout", 30)])?;
+    /// let overrides = ShareMap::<_, _>::try_from_iter([("port", 8080), ("retries", 3)])?;
+    ///
+    /// let config = defaults.union_with(&overrides, |_, _, over| *over);
+    /// assert_eq!(config.len(), 3);
+    /// assert_eq!(config.get("port"), Some(&8080));
+    /// assert_eq!(config.get("timeout"), Some(&30));
+    /// assert_eq!(config.get("retries"), Some(&3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn union_with<OtherMap, F>(&self, other: &ShareMap<K, V, OtherMap>, mut merge: F) -> Self
+    where
+        K: Clone,
+        V: Clone,
+        Map: MapQuery<K, usize> + MapIteration<K, usize> + FromIterator<(K, usize)>,
+        OtherMap: MapQuery<K, usize> + MapIteration<K, usize>,
+        F: FnMut(&K, &V, &V) -> V,
+    {
+        match (self.is_empty(), other.is_empty()) {
+            (_, true) => return Self::share_store(self),
+            (true, false) => return Self::share_store(other),
+            (false, false) => {}
+        }
+
+        let ours = self.iter_insertion_order().map(|(key, value)| {
+            let value = match other.get(key) {
+                Some(other_value) => merge(key, value, other_value),
+                None => value.clone(),
+            };
+            (key.clone(), value)
+        });
+        let theirs = other
+            .iter_insertion_order()
+            .filter(|(key, _)| !self.contains_key(*key))
+            .map(|(key, value)| (key.clone(), value.clone()));
+
+        Self::from_unique_entries(ours.chain(theirs))
+    }
+
+    /// Creates a new [`ShareMap`] containing the entries of this map whose keys are also in
+    /// `other`.
+    ///
+    /// The values of `other` are not used, so it may hold a different value type, and it may use
+    /// a different `Map` type.
+    ///
+    /// The result has its own value store, so the values are cloned, and [`Handle`]s obtained
+    /// from it are not [`Handle::ref_eq`] to [`Handle`]s obtained from this map. Only if every key
+    /// of this map is in `other` does the result share this map's value store instead. Use
+    /// `self.view(|key, _| other.contains_key(key))` to always share it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let prod = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2), ("c", 3)])?;
+    /// let staging = ShareMap::<_, _>::try_from_iter([("b", "x"), ("c", "y"), ("d", "z")])?;
     ///
-    /// assert_eq!(value, Some(&42));
+    /// let both = prod.intersection(&staging);
+    /// assert_eq!(both.len(), 2);
+    /// assert_eq!(both.get("b"), Some(&2));
+    /// assert_eq!(both.get("a"), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    #[must_use]
+    pub fn intersection<W, OtherMap>(&self, other: &ShareMap<K, W, OtherMap>) -> Self
     where
-        Map: MapQuery<Q, usize>,
+        K: Clone,
+        V: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        OtherMap: MapQuery<K, usize>,
     {
-        self.index_map.get(key).map(|index| &self.values[*index])
+        self.entries_where(|key| other.contains_key(key))
     }
 
-    /// Returns the value associated with the given key as a [`Handle`], if it exists.
+    /// Creates a new [`ShareMap`] containing the entries of this map whose keys are not in
+    /// `other`.
     ///
-    /// The returned [`Handle`] will never invalidate, even if the original [`ShareMap`] is
-    /// dropped.
+    /// The values of `other` are not used, so it may hold a different value type, and it may use
+    /// a different `Map` type.
+    ///
+    /// The result has its own value store, so the values are cloned, and [`Handle`]s obtained
+    /// from it are not [`Handle::ref_eq`] to [`Handle`]s obtained from this map. Only if no key of
+    /// this map is in `other` does the result share this map's value store instead. Use
+    /// `self.view(|key, _| !other.contains_key(key))` to always share it.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use share_map::{ShareMap, Handle};
-    ///
-    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
-    ///
-    /// let handle: Handle<i32> = map.get_handle("key1").ok_or("Key not found")?;
-    ///
-    /// assert_eq!(*handle, 42);
+    /// use share_map::ShareMap;
     ///
-    /// // handle is still valid after map is dropped
-    /// drop(map);
+    /// let staging = ShareMap::<_, _>::try_from_iter([("b", 2), ("c", 3), ("d", 4)])?;
+    /// let prod = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2), ("c", 3)])?;
     ///
-    /// assert_eq!(*handle, 42);
+    /// let staging_only = staging.difference(&prod);
+    /// assert_eq!(staging_only.len(), 1);
+    /// assert_eq!(staging_only.get("d"), Some(&4));
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_handle<Q: ?Sized>(&self, key: &Q) -> Option<Handle<V>>
+    #[must_use]
+    pub fn difference<W, OtherMap>(&self, other: &ShareMap<K, W, OtherMap>) -> Self
     where
-        Map: MapQuery<Q, usize>,
+        K: Clone,
+        V: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        OtherMap: MapQuery<K, usize>,
     {
-        self.index_map
-            .get(key)
-            .map(|index| Handle::new(self.values.clone(), *index))
+        self.entries_where(|key| !other.contains_key(key))
     }
 
-    /// Checks if the map contains a specific key.
+    /// Creates a new [`ShareMap`] containing the entries whose keys are in exactly one of the
+    /// two maps.
     ///
-    /// Key equality is determined by the `Map` implementation.
+    /// Entries from this map come first in the result's insertion order, followed by the entries
+    /// from `other`. `other` may use a different `Map` type.
+    ///
+    /// The result has its own value store, so the values are cloned, and [`Handle`]s obtained
+    /// from it are not [`Handle::ref_eq`] to [`Handle`]s obtained from either map. Only if one of
+    /// the maps is empty does the result share the other map's value store instead.
     ///
     /// # Examples
     ///
@@ -180,52 +1151,136 @@ impl<K, V, Map> ShareMap<K, V, Map> {
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use share_map::ShareMap;
     ///
-    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42)])?;
+    /// let left = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2)])?;
+    /// let right = ShareMap::<_, _>::try_from_iter([("b", 20), ("c", 30)])?;
     ///
-    /// assert_eq!(map.contains_key("key1"), true);
-    /// assert_eq!(map.contains_key("key3"), false);
+    /// let either = left.symmetric_difference(&right);
+    /// assert_eq!(either.len(), 2);
+    /// assert_eq!(either.get("a"), Some(&1));
+    /// assert_eq!(either.get("c"), Some(&30));
+    /// assert_eq!(either.get("b"), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    #[must_use]
+    pub fn symmetric_difference<OtherMap>(&self, other: &ShareMap<K, V, OtherMap>) -> Self
     where
-        Map: MapQuery<Q, usize>,
+        K: Clone,
+        V: Clone,
+        Map: MapQuery<K, usize> + MapIteration<K, usize> + FromIterator<(K, usize)>,
+        OtherMap: MapQuery<K, usize> + MapIteration<K, usize>,
     {
-        self.index_map.contains_key(key)
+        match (self.is_empty(), other.is_empty()) {
+            (_, true) => return Self::share_store(self),
+            (true, false) => return Self::share_store(other),
+            (false, false) => {}
+        }
+
+        let ours = self
+            .iter_insertion_order()
+            .filter(|(key, _)| !other.contains_key(*key));
+        let theirs = other
+            .iter_insertion_order()
+            .filter(|(key, _)| !self.contains_key(*key));
+
+        Self::from_unique_entries(
+            ours.chain(theirs)
+                .map(|(key, value)| (key.clone(), value.clone())),
+        )
     }
 
-    /// Returns an iterator over the key-value pairs in the map.
-    ///
-    /// Order of iteration is dependent on the `Map` implementation.
+    /// Creates a new [`ShareMap`] from the entries whose keys match `predicate`, in insertion
+    /// order. If every entry matches, the result shares this map's value store, otherwise the
+    /// matching values are cloned into a new one.
+    fn entries_where<F>(&self, mut predicate: F) -> Self
+    where
+        K: Clone,
+        V: Clone,
+        Map: MapIteration<K, usize> + FromIterator<(K, usize)>,
+        F: FnMut(&K) -> bool,
+    {
+        match self.index_map.keys().all(&mut predicate) {
+            true => Self::share_store(self),
+            false => Self::from_unique_entries(
+                self.iter_insertion_order()
+                    .filter(|(key, _)| predicate(*key))
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            ),
+        }
+    }
+
+    /// Creates a new [`ShareMap`] with a copy of `map`'s index, sharing its value store.
+    fn share_store<OtherMap>(map: &ShareMap<K, V, OtherMap>) -> Self
+    where
+        K: Clone,
+        Map: FromIterator<(K, usize)>,
+        OtherMap: MapIteration<K, usize>,
+    {
+        let index_map = map
+            .index_map
+            .iter()
+            .map(|(key, index)| (key.clone(), *index))
+            .collect();
+
+        Self::new(index_map, map.values.clone())
+    }
+
+    /// Returns the number of key-value pairs in the current map.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// // BTreeMap gurantees iteration order
-    /// let data = [("key1", 42), ("key2", 100)];
-    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    /// let map = ShareMap::<&str, i32>::try_from_iter([("key1", 42), ("key2", 100)])?;
     ///
-    /// let map_keys: Vec<_> = map.iter().collect();
-    /// let data_keys: Vec<_> = data.iter().map(|(k, v)| (k, v)).collect();
+    /// let len = map.len();
     ///
-    /// assert_eq!(map_keys, data_keys);
+    /// assert_eq!(len, 2);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn iter(&self) -> Iter<'_, K, V, Map::Iterator<'_>>
-    where
-        Map: MapIteration<K, usize>,
-    {
-        Iter::new(self.index_map.iter(), &self.values)
+    pub fn len(&self) -> usize {
+        self.values.len()
     }
 
-    /// Returns an iterator over the keys in the map.
+    /// Checks if the map is empty.
     ///
-    /// Order of iteration is dependent on the `Map` implementation.
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<(), ()>::default();
+    /// assert_eq!(map.is_empty(), true);
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)])?;
+    /// assert_eq!(map.is_empty(), false);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Ordered navigation queries, available when `Map` implements [`OrderedMapQuery`].
+///
+/// Only [`BTreeMap`] implements [`OrderedMapQuery`] out of the box. The frozen maps, including
+/// [`FzOrderedMap`](frozen_collections::FzOrderedMap), expose no range lookups, so these queries
+/// are not available for them.
+impl<K, V, Map> ShareMap<K, V, Map>
+where
+    Map: OrderedMapQuery<K, usize>,
+{
+    fn resolve_entry<'a>(&'a self, (key, index): (&'a K, &'a usize)) -> (&'a K, &'a V) {
+        (key, &self.values[*index])
+    }
+
+    /// Returns an iterator over the key-value pairs with keys within `range`, in ascending key
+    /// order.
     ///
     /// # Examples
     ///
@@ -234,148 +1289,196 @@ impl<K, V, Map> ShareMap<K, V, Map> {
     /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// // BTreeMap gurantees iteration order
-    /// let data = [("key1", 42), ("key2", 100)];
+    /// let data = [(1, "a"), (2, "b"), (3, "c"), (4, "d")];
     /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
     ///
-    /// let map_keys: Vec<_> = map.keys().collect();
-    /// let data_keys: Vec<_> = data.iter().map(|(k, _)| k).collect();
+    /// let pairs: Vec<_> = map.range(2..4).collect();
+    /// assert_eq!(pairs, [(&2, &"b"), (&3, &"c")]);
     ///
-    /// assert_eq!(map_keys, data_keys);
+    /// let pairs: Vec<_> = map.range(3..).rev().collect();
+    /// assert_eq!(pairs, [(&4, &"d"), (&3, &"c")]);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn keys(&self) -> Map::KeyIterator<'_>
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V, Map::RangeIterator<'_>>
     where
-        Map: MapIteration<K, usize>,
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
     {
-        self.index_map.keys()
+        Iter::new(self.index_map.range(range), &self.values)
     }
 
-    /// Returns an iterator over the values in the map.
+    /// Returns the key-value pair with the smallest key, if any.
     ///
-    /// Unlike [`HashMap::values`], this method is `O(n:len)`, not `O(n:capacity)`.
+    /// # Examples
     ///
-    /// Values iteration order is not defined.
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
     ///
-    /// # Example
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(2, "b"), (1, "a")])?;
+    ///
+    /// assert_eq!(map.first_key_value(), Some((&1, &"a")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.index_map
+            .first_key_value()
+            .map(|entry| self.resolve_entry(entry))
+    }
+
+    /// Returns the key-value pair with the largest key, if any.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use std::collections::HashSet;
+    /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// let data = [("key1", 42), ("key2", 100)];
-    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
-    ///
-    /// let map_values = map.values();
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([(2, "b"), (1, "a")])?;
     ///
-    /// // value order is not defined, so compare as sets
-    /// let data_set: HashSet<_> = data.iter().map(|(_, v)| v).collect();
-    /// let share_set: HashSet<_> = map_values.collect();
-    /// assert_eq!(data_set, share_set);
+    /// assert_eq!(map.last_key_value(), Some((&2, &"b")));
     /// # Ok(())
     /// # }
     /// ```
-    pub fn values(&self) -> std::slice::Iter<'_, V> {
-        self.values.iter()
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.index_map
+            .last_key_value()
+            .map(|entry| self.resolve_entry(entry))
     }
 
-    /// Consumes the [`ShareMap`] and returns a key (`K`) iterator.
+    /// Returns the key-value pair with the largest key less than or equal to `key`, if any.
     ///
-    /// Order of iteration is dependent on the `Map` implementation.
-    ///
-    /// # Example
+    /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// // BTreeMap gurantees iteration order
-    /// let data = [("key1", 42), ("key2", 100)];
+    /// let data = [(10, "v1.0"), (20, "v2.0"), (30, "v3.0")];
     /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
     ///
-    /// let map_keys: Vec<_> = map.into_keys().collect();
-    /// let data_keys: Vec<_> = data.into_iter().map(|(k, _)| k).collect();
-    ///
-    /// assert_eq!(map_keys, data_keys);
+    /// assert_eq!(map.floor(&25), Some((&20, &"v2.0")));
+    /// assert_eq!(map.floor(&20), Some((&20, &"v2.0")));
+    /// assert_eq!(map.floor(&5), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn into_keys(self) -> Map::IntoKeyIterator
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
     where
-        Map: MapIteration<K, usize>,
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
     {
-        self.index_map.into_keys()
+        self.range((Bound::Unbounded, Bound::Included(key)))
+            .next_back()
     }
 
-    /// Consumes the [`ShareMap`] and returns the value store.
+    /// Returns the key-value pair with the smallest key greater than or equal to `key`, if any.
     ///
-    /// The order of the values is not defined.
-    ///
-    /// # Example
+    /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use std::sync::Arc;
-    /// use std::collections::HashSet;
+    /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// let data = [("key1", 42), ("key2", 100)];
-    /// let map = ShareMap::<_, _>::try_from_iter(data)?;
-    ///
-    /// let map_values: Arc<[i32]> = map.into_values();
+    /// let data = [(10, "v1.0"), (20, "v2.0"), (30, "v3.0")];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
     ///
-    /// // value order is not defined, so compare as sets
-    /// let data_set: HashSet<_> = data.iter().map(|(_, v)| v).collect();
-    /// let share_set: HashSet<_> = map_values.iter().collect();
-    /// assert_eq!(data_set, share_set);
+    /// assert_eq!(map.ceiling(&25), Some((&30, &"v3.0")));
+    /// assert_eq!(map.ceiling(&30), Some((&30, &"v3.0")));
+    /// assert_eq!(map.ceiling(&35), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn into_values(self) -> Arc<[V]> {
-        self.values
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.range((Bound::Included(key), Bound::Unbounded)).next()
     }
 
-    /// Returns the number of key-value pairs in the current map.
+    /// Returns the key-value pair with the largest key strictly less than `key`, if any.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// let map = ShareMap::<&str, i32>::try_from_iter([("key1", 42), ("key2", 100)])?;
+    /// let data = [(10, "v1.0"), (20, "v2.0"), (30, "v3.0")];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
     ///
-    /// let len = map.len();
+    /// assert_eq!(map.predecessor(&20), Some((&10, &"v1.0")));
+    /// assert_eq!(map.predecessor(&10), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn predecessor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.range((Bound::Unbounded, Bound::Excluded(key)))
+            .next_back()
+    }
+
+    /// Returns the key-value pair with the smallest key strictly greater than `key`, if any.
     ///
-    /// assert_eq!(len, 2);
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [(10, "v1.0"), (20, "v2.0"), (30, "v3.0")];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    ///
+    /// assert_eq!(map.successor(&20), Some((&30, &"v3.0")));
+    /// assert_eq!(map.successor(&30), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn len(&self) -> usize {
-        self.values.len()
+    pub fn successor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.range((Bound::Excluded(key), Bound::Unbounded)).next()
     }
 
-    /// Checks if the map is empty.
+    /// Returns an iterator over the key-value pairs whose keys start with `prefix`, in ascending
+    /// key order.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
     /// use share_map::ShareMap;
     ///
-    /// let map = ShareMap::<(), ()>::default();
-    /// assert_eq!(map.is_empty(), true);
+    /// let data = [("app.name", 1), ("app.port", 2), ("db.host", 3)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
     ///
-    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)])?;
-    /// assert_eq!(map.is_empty(), false);
+    /// let keys: Vec<_> = map.prefix("app.").map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, ["app.name", "app.port"]);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.values.is_empty()
+    pub fn prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: Borrow<str>,
+    {
+        self.range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(key, _)| Borrow::<str>::borrow(*key).starts_with(prefix))
     }
 }
 
@@ -393,7 +1496,7 @@ impl<K, V, Map: Default> Default for ShareMap<K, V, Map> {
     fn default() -> Self {
         Self {
             index_map: Map::default(),
-            values: Arc::default(),
+            values: Store::default(),
             _marker: std::marker::PhantomData,
         }
     }
@@ -437,6 +1540,21 @@ where
     }
 }
 
+/// Consumes the [`ShareMap`], yielding each key and a [`Handle`] to its value.
+///
+/// Order of iteration is dependent on the `Map` implementation.
+impl<K, V, Map> IntoIterator for ShareMap<K, V, Map>
+where
+    Map: MapIteration<K, usize>,
+{
+    type Item = (K, Handle<V>);
+    type IntoIter = IntoIter<K, V, <Map as IntoIterator>::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.index_map.into_iter(), self.values)
+    }
+}
+
 impl<K, Q, V, Map> Index<Q> for ShareMap<K, V, Map>
 where
     Map: Index<Q, Output = usize>,
@@ -469,18 +1587,17 @@ where
     }
 }
 
+/// Values are moved out of the [`ShareMap`] if its value store is not shared, and cloned
+/// otherwise.
 impl<K, V, Map, S> From<ShareMap<K, V, Map>> for HashMap<K, V, S>
 where
-    K: Eq + std::hash::Hash + Clone,
+    K: Eq + std::hash::Hash,
     V: Clone,
     Map: MapIteration<K, usize>,
     S: std::hash::BuildHasher + Default,
 {
     fn from(value: ShareMap<K, V, Map>) -> Self {
-        value
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect()
+        value.into_owned_entries().into_iter().collect()
     }
 }
 
@@ -493,17 +1610,16 @@ where
     }
 }
 
+/// Values are moved out of the [`ShareMap`] if its value store is not shared, and cloned
+/// otherwise.
 impl<K, V, Map> From<ShareMap<K, V, Map>> for BTreeMap<K, V>
 where
-    K: Eq + std::hash::Hash + Clone + Ord,
+    K: Ord,
     V: Clone,
     Map: MapIteration<K, usize>,
 {
     fn from(value: ShareMap<K, V, Map>) -> Self {
-        value
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect()
+        value.into_owned_entries().into_iter().collect()
     }
 }
 
@@ -516,7 +1632,8 @@ where
     /// Unless duplicate keys are allowed, prefer [`ShareMap::try_from_iter`] or the corresponding
     /// [`TryCollectEx::try_collect_ex`] extension instead.
     ///
-    /// In the case of duplicate keys, the value stored depends on the map implementation.
+    /// In the case of duplicate keys, the value stored depends on the map implementation. The
+    /// value store keeps the stored values in the order they appeared in `iterable`.
     ///
     /// # Examples
     ///
@@ -536,53 +1653,13 @@ where
     /// assert_eq!(map["key1"], 2);
     /// ```
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iterable: T) -> Self {
-        let (mut values, mut key_index_pairs): (Vec<_>, Vec<_>) = iterable
+        let (values, key_index_pairs): (Vec<_>, Vec<_>) = iterable
             .into_iter()
             .enumerate()
             .map(|(index, (key, value))| (Some(value), (key, index)))
             .unzip();
 
-        // converting the key_index_pairs into a map should remove duplicates
-        let index_map: Map = key_index_pairs.drain(..).collect();
-
-        match usize::cmp(&index_map.len(), &values.len()) {
-            Ordering::Equal => {
-                // PANIC SAFETY: all values in store are Some
-                let store = values.into_iter().map(Option::unwrap).collect();
-                Self::new(index_map, store)
-            }
-            Ordering::Greater => panic!("Invalid map implementation"),
-            Ordering::Less => {
-                // in the event of duplicates, rebuild the index_map and store
-                let index_map_len = index_map.len();
-
-                let (key_index_pairs, values) = index_map
-                    .into_iter()
-                    .enumerate()
-                    .map(|(index, (key, old_index))| {
-                        // PANIC SAFETY: all values in store are Some
-                        ((key, index), values[old_index].take().unwrap())
-                    })
-                    // fold is used instead of zip to reuse key_index_pairs
-                    .fold(
-                        (key_index_pairs, Vec::with_capacity(index_map_len)),
-                        |(mut key_index_pairs, mut new_values), (key_index_pair, value)| {
-                            new_values.push(value);
-                            key_index_pairs.push(key_index_pair);
-                            (key_index_pairs, new_values)
-                        },
-                    );
-
-                let index_map: Map = Map::from_iter(key_index_pairs);
-
-                assert!(
-                    index_map.len() == values.len() && values.len() == index_map_len,
-                    "Invalid map implementation"
-                );
-
-                Self::new(index_map, values.into())
-            }
-        }
+        Self::from_indexed(key_index_pairs, values)
     }
 }
 
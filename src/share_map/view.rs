@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use frozen_collections::{Len, MapIteration, MapQuery};
+
+#[cfg(doc)]
+use crate::ShareMap;
+use crate::handle::Store;
+use crate::{Handle, Iter};
+
+/// A read-only subset of a [`ShareMap`], sharing its value store.
+///
+/// A [`ShareMapView`] holds its own index of the keys it contains, but no values of its own, so
+/// creating one never copies values. [`Handle`]s obtained through a view are
+/// [`Handle::ref_eq`] to [`Handle`]s obtained from the parent map.
+///
+/// Created by [`ShareMap::view`].
+///
+/// # Type Parameters
+/// - `K`: The key type stored in the view
+/// - `V`: The value type stored in the parent map.
+/// - `Map`: The map used to map keys to internal indices.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::{Handle, ShareMap};
+///
+/// let data = [("eu/paris", 1), ("us/ohio", 2), ("eu/berlin", 3)];
+/// let map = ShareMap::<_, _>::try_from_iter(data)?;
+///
+/// let eu = map.view(|key, _| key.starts_with("eu/"));
+/// assert_eq!(eu.len(), 2);
+/// assert_eq!(eu.get("eu/paris"), Some(&1));
+/// assert_eq!(eu.get("us/ohio"), None);
+///
+/// let from_view = eu.get_handle("eu/berlin").ok_or("Key not found")?;
+/// let from_map = map.get_handle("eu/berlin").ok_or("Key not found")?;
+/// assert!(Handle::ref_eq(&from_view, &from_map));
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShareMapView<K, V, Map = HashMap<K, usize>> {
+    index_map: Map,
+    values: Store<V>,
+    _marker: std::marker::PhantomData<K>,
+}
+
+// manual implementation is necessary because #Derive would require K: Clone and V: Clone
+impl<K, V, Map: Clone> Clone for ShareMapView<K, V, Map> {
+    fn clone(&self) -> Self {
+        Self::new(self.index_map.clone(), self.values.clone())
+    }
+}
+
+impl<K, V, Map> ShareMapView<K, V, Map> {
+    pub(crate) fn new(index_map: Map, values: Store<V>) -> Self {
+        Self {
+            index_map,
+            values,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the value associated with the given key, if it is in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    /// let view = map.view(|_, value| *value > 1);
+    ///
+    /// assert_eq!(view.get("key2"), Some(&2));
+    /// assert_eq!(view.get("key1"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map.get(key).map(|index| &self.values[*index])
+    }
+
+    /// Returns the value associated with the given key as a [`Handle`], if it is in the view.
+    ///
+    /// The returned [`Handle`] points into the value store of the parent [`ShareMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::{Handle, ShareMap};
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    /// let view = map.view(|_, value| *value > 1);
+    /// drop(map);
+    ///
+    /// let handle: Handle<i32> = view.get_handle("key2").ok_or("Key not found")?;
+    /// drop(view);
+    ///
+    /// assert_eq!(*handle, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_handle<Q: ?Sized>(&self, key: &Q) -> Option<Handle<V>>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map
+            .get(key)
+            .map(|index| Handle::new(self.values.clone(), *index))
+    }
+
+    /// Checks if the view contains a specific key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    /// let view = map.view(|key, _| *key == "key1");
+    ///
+    /// assert!(view.contains_key("key1"));
+    /// assert!(!view.contains_key("key2"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map.contains_key(key)
+    }
+
+    /// Returns an iterator over the key-value pairs in the view.
+    ///
+    /// Order of iteration is dependent on the `Map` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// let data = [("key1", 1), ("key2", 2), ("key3", 3)];
+    /// let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(data)?;
+    /// let view = map.view(|_, value| value % 2 == 1);
+    ///
+    /// let pairs: Vec<_> = view.iter().collect();
+    /// assert_eq!(pairs, [(&"key1", &1), (&"key3", &3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V, Map::Iterator<'_>>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        Iter::new(self.index_map.iter(), &self.values)
+    }
+
+    /// Returns the number of key-value pairs in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1), ("key2", 2)])?;
+    ///
+    /// assert_eq!(map.view(|_, _| true).len(), 2);
+    /// assert_eq!(map.view(|_, _| false).len(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize
+    where
+        Map: Len,
+    {
+        self.index_map.len()
+    }
+
+    /// Checks if the view is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<_, _>::try_from_iter([("key1", 1)])?;
+    ///
+    /// assert!(map.view(|_, _| false).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_empty(&self) -> bool
+    where
+        Map: Len,
+    {
+        self.index_map.len() == 0
+    }
+}
+
+impl<K: Debug, V: Debug, Map> Debug for ShareMapView<K, V, Map>
+where
+    Map: MapIteration<K, usize>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<'a, K, V, Map> IntoIterator for &'a ShareMapView<K, V, Map>
+where
+    Map: MapIteration<K, usize>,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, Map::Iterator<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
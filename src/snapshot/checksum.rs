@@ -0,0 +1,72 @@
+/// A running CRC-32 (IEEE) checksum.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Crc32(u32);
+
+impl Crc32 {
+    const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+    /// The checksum of each possible byte, so bytes are processed whole instead of bit by bit.
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut byte: u32 = 0;
+        while byte < 256 {
+            let mut crc = byte;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = match crc & 1 {
+                    1 => (crc >> 1) ^ Self::POLYNOMIAL,
+                    _ => crc >> 1,
+                };
+                bit += 1;
+            }
+            table[byte as usize] = crc;
+            byte += 1;
+        }
+        table
+    };
+
+    pub(crate) fn new() -> Self {
+        Self(u32::MAX)
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            let index = (self.0 ^ u32::from(*byte)) & 0xFF;
+            self.0 = (self.0 >> 8) ^ Self::TABLE[index as usize];
+        }
+    }
+
+    pub(crate) fn finish(self) -> u32 {
+        !self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc32;
+
+    #[test]
+    fn matches_check_value() {
+        let mut crc = Crc32::new();
+        crc.update(b"123456789");
+
+        assert_eq!(crc.finish(), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn incremental_matches_whole() {
+        let mut whole = Crc32::new();
+        whole.update(b"hello world");
+
+        let mut parts = Crc32::new();
+        parts.update(b"hello");
+        parts.update(b" world");
+
+        assert_eq!(whole.finish(), parts.finish());
+    }
+
+    #[test]
+    fn empty_is_zero() {
+        assert_eq!(Crc32::new().finish(), 0);
+    }
+}
@@ -0,0 +1,43 @@
+/// Encodes and decodes the keys and values of a [`ShareMap`](crate::ShareMap) snapshot.
+///
+/// A codec is a separate type from the values it encodes, so the same type can be written with
+/// different codecs, and types that do not implement serde can be written with a custom codec.
+/// [`JsonCodec`] is used unless another codec is given, see
+/// [`ShareMap::write_snapshot_with`](crate::ShareMap::write_snapshot_with).
+pub trait SnapshotCodec<T> {
+    /// The error returned when encoding or decoding fails.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Appends the encoded form of `value` to `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if `value` cannot be encoded.
+    fn encode(&self, value: &T, buf: &mut Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Decodes a value from exactly the bytes written by [`SnapshotCodec::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if `bytes` is not a valid encoding.
+    fn decode(&self, bytes: &[u8]) -> Result<T, Self::Error>;
+}
+
+/// The default [`SnapshotCodec`], encoding any serde type as JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonCodec;
+
+impl<T> SnapshotCodec<T> for JsonCodec
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    fn encode(&self, value: &T, buf: &mut Vec<u8>) -> Result<(), Self::Error> {
+        serde_json::to_writer(buf, value)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<T, Self::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
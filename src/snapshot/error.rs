@@ -0,0 +1,38 @@
+use crate::DuplicateKeyError;
+
+/// An error returned when writing or reading a [`ShareMap`](crate::ShareMap) snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    /// An I/O error occurred, other than reaching the end of the data early.
+    #[error("snapshot i/o error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The data does not start with the snapshot magic bytes.
+    #[error("not a share_map snapshot")]
+    BadMagic,
+    /// The snapshot was written in a format version this crate cannot read.
+    #[error("unsupported snapshot version {0}")]
+    UnsupportedVersion(u16),
+    /// The data ended before the snapshot was complete.
+    #[error("snapshot is truncated")]
+    Truncated,
+    /// The data continues after the end of the snapshot.
+    #[error("snapshot is followed by trailing data")]
+    TrailingData,
+    /// The checksum stored in the snapshot does not match its contents.
+    #[error("snapshot checksum mismatch: expected {expected:#010x}, found {actual:#010x}")]
+    ChecksumMismatch {
+        /// The checksum stored in the snapshot.
+        expected: u32,
+        /// The checksum of the data read.
+        actual: u32,
+    },
+    /// A key or value could not be encoded.
+    #[error("failed to encode snapshot entry: {0}")]
+    Encode(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// A key or value could not be decoded.
+    #[error("failed to decode snapshot entry: {0}")]
+    Decode(#[source] Box<dyn std::error::Error + Send + Sync>),
+    /// The snapshot contains duplicate keys.
+    #[error(transparent)]
+    DuplicateKey(#[from] DuplicateKeyError),
+}
@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Deref, Range};
+use std::path::Path;
+use std::sync::Arc;
+
+use frozen_collections::{Len, MapIteration, MapQuery};
+use memmap2::Mmap;
+
+use super::checksum::Crc32;
+use super::{JsonCodec, MAGIC, SnapshotCodec, SnapshotError, VERSION, decode};
+use crate::DuplicateKeyError;
+
+/// A read-only map backed by a memory-mapped snapshot file.
+///
+/// Opening the map checks the layout of the snapshot and decodes its keys into an index, but
+/// values stay in the mapped pages and are only decoded on request, via
+/// [`MappedBytes::decode`], with the map's [`SnapshotCodec`]. The checksum covers the whole file,
+/// so it is only verified on request, via [`MappedShareMap::verify`]. Because the file is mapped
+/// read-only and shared, several processes opening the same snapshot share one physical copy of
+/// its values.
+///
+/// Lookups return [`MappedBytes`], the still encoded bytes of a value, which keep the mapping
+/// alive the way a [`Handle`](crate::Handle) keeps its value store alive.
+///
+/// Snapshots are written by [`ShareMap::write_snapshot`](crate::ShareMap::write_snapshot), see
+/// the [`snapshot`](super) module for the format.
+///
+/// # Type Parameters
+/// - `K`: The key type stored in the map.
+/// - `V`: The value type stored in the snapshot.
+/// - `Map`: The map used to map keys to entry indices.
+/// - `C`: The [`SnapshotCodec`] keys and values are decoded with.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use share_map::ShareMap;
+/// use share_map::snapshot::MappedShareMap;
+///
+/// let map = ShareMap::<String, u32>::try_from_iter([("key1".into(), 42), ("key2".into(), 100)])?;
+///
+/// let path = std::env::temp_dir().join(format!("share_map_doc_{}.shmp", std::process::id()));
+/// map.write_snapshot(std::fs::File::create(&path)?)?;
+///
+/// // SAFETY: the file is not modified while it is mapped
+/// let mapped = unsafe { MappedShareMap::<String, u32>::open(&path)? };
+/// mapped.verify()?;
+/// let value = mapped.get("key1").ok_or("Key not found")?;
+/// assert_eq!(value.decode()?, 42);
+/// assert!(!mapped.contains_key("key3"));
+/// # drop((mapped, value));
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MappedShareMap<K, V, Map = HashMap<K, usize>, C = JsonCodec> {
+    index_map: Map,
+    ranges: Vec<Range<usize>>,
+    mapping: Arc<Mapping<C>>,
+    _marker: Decodes<K, V>,
+}
+
+/// A mapped snapshot file, and the codec its values are decoded with.
+struct Mapping<C> {
+    mmap: Mmap,
+    codec: C,
+}
+
+impl<K, V, Map> MappedShareMap<K, V, Map> {
+    /// Memory-maps the snapshot file at `path` and indexes its keys, decoding them with
+    /// [`JsonCodec`].
+    ///
+    /// Only the header and the entry lengths are read to locate the entries, and the keys are
+    /// decoded to build the index. Values are not read, and neither is the checksum, which
+    /// would require reading the whole file. Call [`MappedShareMap::verify`] to verify it.
+    ///
+    /// # Safety
+    ///
+    /// The map and every [`MappedBytes`] obtained from it borrow the pages of the file directly,
+    /// as `&[u8]`. The file must therefore not be modified or truncated while it is mapped, that
+    /// is until the map and all [`MappedBytes`] obtained from it are dropped:
+    ///
+    /// - Writing to the file, through this or any other process, changes bytes behind a shared
+    ///   reference, which is undefined behavior. This includes writing a new snapshot to the same
+    ///   path with [`File::create`], which truncates the existing file.
+    /// - Truncating the file makes reads of the removed pages fault, typically with `SIGBUS`.
+    ///
+    /// Replacing the file with a new one, for example by writing a new snapshot to a temporary
+    /// file and renaming it over the path, is fine, as the mapping keeps the old file alive.
+    /// The operating system does not enforce any of this, so it is up to the caller to ensure
+    /// no other process modifies the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotError::Io`] if the file cannot be opened or mapped.
+    /// - [`SnapshotError::BadMagic`] or [`SnapshotError::UnsupportedVersion`] if the header is
+    ///   invalid.
+    /// - [`SnapshotError::Truncated`] or [`SnapshotError::TrailingData`] if the file is shorter
+    ///   or longer than its entries.
+    /// - [`SnapshotError::Decode`] if a key cannot be decoded.
+    /// - [`SnapshotError::DuplicateKey`] if the snapshot contains duplicate keys.
+    pub unsafe fn open(path: impl AsRef<Path>) -> Result<Self, SnapshotError>
+    where
+        JsonCodec: SnapshotCodec<K>,
+        Map: FromIterator<(K, usize)> + Len,
+    {
+        // SAFETY: the caller upholds the same requirements
+        unsafe { Self::open_with(JsonCodec, path) }
+    }
+}
+
+impl<K, V, Map, C> MappedShareMap<K, V, Map, C> {
+    /// Memory-maps the snapshot file at `path` and indexes its keys, decoding keys and values
+    /// with `codec`.
+    ///
+    /// See [`MappedShareMap::open`].
+    ///
+    /// # Safety
+    ///
+    /// The same as for [`MappedShareMap::open`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MappedShareMap::open`].
+    pub unsafe fn open_with(codec: C, path: impl AsRef<Path>) -> Result<Self, SnapshotError>
+    where
+        C: SnapshotCodec<K>,
+        Map: FromIterator<(K, usize)> + Len,
+    {
+        let file = File::open(path)?;
+        // SAFETY: the caller guarantees the file is not modified while mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let (keys, ranges) = locate(&mmap)?;
+        let count = ranges.len();
+
+        let index_map = keys
+            .into_iter()
+            .enumerate()
+            .map(|(index, range)| Ok((decode(&codec, &mmap[range])?, index)))
+            .collect::<Result<Map, SnapshotError>>()?;
+
+        match index_map.len() == count {
+            true => Ok(Self {
+                index_map,
+                ranges,
+                mapping: Arc::new(Mapping { mmap, codec }),
+                _marker: PhantomData,
+            }),
+            false => Err(DuplicateKeyError.into()),
+        }
+    }
+
+    /// Verifies the checksum of the snapshot, reading the whole file.
+    ///
+    /// [`MappedShareMap::open`] only checks the layout of the snapshot, so a corrupted value is
+    /// otherwise only noticed if it fails to decode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::ChecksumMismatch`] if the snapshot is corrupted.
+    pub fn verify(&self) -> Result<(), SnapshotError> {
+        // `locate` checked that the snapshot ends with the checksum
+        let (data, checksum) = self
+            .mapping
+            .mmap
+            .split_last_chunk()
+            .ok_or(SnapshotError::Truncated)?;
+
+        let mut crc = Crc32::new();
+        crc.update(data);
+        let actual = crc.finish();
+        let expected = u32::from_le_bytes(*checksum);
+
+        match expected == actual {
+            true => Ok(()),
+            false => Err(SnapshotError::ChecksumMismatch { expected, actual }),
+        }
+    }
+
+    /// Returns the [`MappedBytes`] of the value associated with the given key, if it exists.
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<MappedBytes<V, C>>
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map
+            .get(key)
+            .map(|&index| MappedBytes::new(&self.mapping, self.ranges[index].clone()))
+    }
+
+    /// Checks if the map contains a specific key.
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        Map: MapQuery<Q, usize>,
+    {
+        self.index_map.contains_key(key)
+    }
+
+    /// Returns an iterator over the keys and the [`MappedBytes`] of their values.
+    ///
+    /// The iteration order is determined by the `Map` implementation.
+    pub fn iter(&self) -> MappedIter<'_, K, V, Map::Iterator<'_>, C>
+    where
+        Map: MapIteration<K, usize>,
+    {
+        MappedIter {
+            index_iter: self.index_map.iter(),
+            ranges: &self.ranges,
+            mapping: &self.mapping,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize
+    where
+        Map: Len,
+    {
+        self.index_map.len()
+    }
+
+    /// Checks if the map is empty.
+    pub fn is_empty(&self) -> bool
+    where
+        Map: Len,
+    {
+        self.index_map.is_empty()
+    }
+}
+
+/// Marks a type that decodes keys of type `K` and values of type `V`, without owning any.
+type Decodes<K, V> = PhantomData<(fn() -> K, fn() -> V)>;
+
+impl<'a, K, V, Map, C> IntoIterator for &'a MappedShareMap<K, V, Map, C>
+where
+    Map: MapIteration<K, usize>,
+{
+    type Item = (&'a K, MappedBytes<V, C>);
+    type IntoIter = MappedIter<'a, K, V, Map::Iterator<'a>, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Debug, V, Map: MapIteration<K, usize>, C> Debug for MappedShareMap<K, V, Map, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(key, value)| (key, value.len())))
+            .finish()
+    }
+}
+
+/// The encoded bytes of a value in a [`MappedShareMap`], which keep the mapping alive.
+///
+/// Dereferences to the encoded bytes, in the format of the map's [`SnapshotCodec`]. The value
+/// itself is only produced by [`MappedBytes::decode`], which decodes a new `V` on every call.
+pub struct MappedBytes<V, C = JsonCodec> {
+    mapping: Arc<Mapping<C>>,
+    range: Range<usize>,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V, C> MappedBytes<V, C> {
+    fn new(mapping: &Arc<Mapping<C>>, range: Range<usize>) -> Self {
+        Self {
+            mapping: Arc::clone(mapping),
+            range,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decodes the value with the [`SnapshotCodec`] of the [`MappedShareMap`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Decode`] if the value cannot be decoded.
+    pub fn decode(&self) -> Result<V, SnapshotError>
+    where
+        C: SnapshotCodec<V>,
+    {
+        decode(&self.mapping.codec, self)
+    }
+}
+
+impl<V, C> Deref for MappedBytes<V, C> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.mapping.mmap[self.range.clone()]
+    }
+}
+
+impl<V, C> AsRef<[u8]> for MappedBytes<V, C> {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+// manual implementation is necessary because #Derive would require V: Clone and C: Clone
+impl<V, C> Clone for MappedBytes<V, C> {
+    fn clone(&self) -> Self {
+        Self::new(&self.mapping, self.range.clone())
+    }
+}
+
+impl<V, C> Debug for MappedBytes<V, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedBytes")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over the entries of a [`MappedShareMap`].
+///
+/// Created by [`MappedShareMap::iter`].
+pub struct MappedIter<'a, K: 'a, V, I, C = JsonCodec>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    index_iter: I,
+    ranges: &'a [Range<usize>],
+    mapping: &'a Arc<Mapping<C>>,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<'a, K, V, I, C> Debug for MappedIter<'a, K, V, I, C>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedIter").finish_non_exhaustive()
+    }
+}
+
+impl<'a, K, V, I, C> Iterator for MappedIter<'a, K, V, I, C>
+where
+    I: Iterator<Item = (&'a K, &'a usize)>,
+{
+    type Item = (&'a K, MappedBytes<V, C>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index_iter.next().map(|(key, &index)| {
+            (
+                key,
+                MappedBytes::new(self.mapping, self.ranges[index].clone()),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index_iter.size_hint()
+    }
+}
+
+impl<'a, K, V, I, C> DoubleEndedIterator for MappedIter<'a, K, V, I, C>
+where
+    I: DoubleEndedIterator<Item = (&'a K, &'a usize)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.index_iter.next_back().map(|(key, &index)| {
+            (
+                key,
+                MappedBytes::new(self.mapping, self.ranges[index].clone()),
+            )
+        })
+    }
+}
+
+impl<'a, K, V, I, C> ExactSizeIterator for MappedIter<'a, K, V, I, C> where
+    I: ExactSizeIterator<Item = (&'a K, &'a usize)>
+{
+}
+
+impl<'a, K, V, I, C> FusedIterator for MappedIter<'a, K, V, I, C> where
+    I: FusedIterator<Item = (&'a K, &'a usize)>
+{
+}
+
+/// The byte ranges of the encoded keys and values in a snapshot.
+type Entries = (Vec<Range<usize>>, Vec<Range<usize>>);
+
+/// Checks the layout of a snapshot and locates its entries, without decoding them or verifying
+/// the checksum.
+fn locate(bytes: &[u8]) -> Result<Entries, SnapshotError> {
+    let mut cursor = Cursor { bytes, pos: 0 };
+
+    if cursor.take(MAGIC.len())? != MAGIC {
+        return Err(SnapshotError::BadMagic);
+    }
+
+    match u16::from_le_bytes(cursor.take_array()?) {
+        VERSION => {}
+        version => return Err(SnapshotError::UnsupportedVersion(version)),
+    }
+
+    let count = u64::from_le_bytes(cursor.take_array()?);
+
+    // the count is untrusted, as the checksum is not verified, so don't allocate for it all
+    let capacity = usize::try_from(count).unwrap_or(usize::MAX).min(1024);
+    let mut keys = Vec::with_capacity(capacity);
+    let mut values = Vec::with_capacity(capacity);
+    for _ in 0..count {
+        keys.push(cursor.take_block()?);
+        values.push(cursor.take_block()?);
+    }
+
+    cursor.take(size_of::<u32>())?;
+
+    match cursor.pos == bytes.len() {
+        true => Ok((keys, values)),
+        false => Err(SnapshotError::TrailingData),
+    }
+}
+
+/// A position in a snapshot, reporting reads past the end as truncation.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take_range(&mut self, len: usize) -> Result<Range<usize>, SnapshotError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(SnapshotError::Truncated)?;
+        let range = self.pos..end;
+        self.pos = end;
+        Ok(range)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SnapshotError> {
+        let range = self.take_range(len)?;
+        Ok(&self.bytes[range])
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    /// Takes a block written by `ChecksumWriter::write_block`.
+    fn take_block(&mut self) -> Result<Range<usize>, SnapshotError> {
+        let len = u64::from_le_bytes(self.take_array()?);
+        let len = usize::try_from(len).map_err(|_| SnapshotError::Truncated)?;
+        self.take_range(len)
+    }
+}
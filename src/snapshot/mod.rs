@@ -0,0 +1,351 @@
+//! A versioned binary snapshot format for [`ShareMap`], with integrity checks.
+//!
+//! See [`ShareMap::write_snapshot`] and [`ShareMap::read_snapshot`].
+//!
+//! # Format
+//!
+//! All integers are little-endian.
+//!
+//! | Field        | Size          | Description                                    |
+//! |--------------|---------------|------------------------------------------------|
+//! | magic        | 4             | `b"SHMP"`                                      |
+//! | version      | 2             | Format version, currently `1`                  |
+//! | count        | 8             | Number of entries                              |
+//! | entries      | variable      | `count` entries, in insertion order            |
+//! | checksum     | 4             | CRC-32 (IEEE) of all preceding bytes           |
+//!
+//! Each entry is the key length (8 bytes), the encoded key, the value length (8 bytes) and the
+//! encoded value. Keys and values are encoded with a [`SnapshotCodec`], [`JsonCodec`] unless
+//! another one is given. The layout does not depend on the `Map` implementation, so a snapshot
+//! can be read into a [`ShareMap`] with any backend. Nothing may follow the checksum.
+//!
+//! With the `mmap` feature, a snapshot file can also be opened in place as a
+//! `MappedShareMap`, which decodes values only on access.
+
+mod checksum;
+mod codec;
+mod error;
+#[cfg(feature = "mmap")]
+mod mapped;
+
+use std::io::{ErrorKind, Read, Write};
+
+pub use codec::{JsonCodec, SnapshotCodec};
+pub use error::SnapshotError;
+#[cfg(feature = "mmap")]
+pub use mapped::{MappedBytes, MappedIter, MappedShareMap};
+
+use crate::{Len, MapIteration, ShareMap};
+use checksum::Crc32;
+
+const MAGIC: [u8; 4] = *b"SHMP";
+const VERSION: u16 = 1;
+
+impl<K, V, Map> ShareMap<K, V, Map> {
+    /// Writes a snapshot of the map to `writer`, see the [`snapshot`](crate::snapshot) module
+    /// for the format.
+    ///
+    /// Entries are written in insertion order, and encoded with [`JsonCodec`]. To use another
+    /// codec, see [`ShareMap::write_snapshot_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Encode`] if a key or value cannot be encoded, and
+    /// [`SnapshotError::Io`] if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::BTreeMap;
+    /// use share_map::ShareMap;
+    ///
+    /// let map = ShareMap::<String, u32>::try_from_iter([("key1".into(), 42), ("key2".into(), 100)])?;
+    ///
+    /// let mut snapshot = Vec::new();
+    /// map.write_snapshot(&mut snapshot)?;
+    ///
+    /// // the snapshot can be read into a map with a different backend
+    /// let restored = ShareMap::<String, u32, BTreeMap<_, _>>::read_snapshot(snapshot.as_slice())?;
+    /// assert_eq!(restored.get("key1"), Some(&42));
+    /// assert_eq!(restored.get("key2"), Some(&100));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_snapshot<W: Write>(&self, writer: W) -> Result<(), SnapshotError>
+    where
+        JsonCodec: SnapshotCodec<K> + SnapshotCodec<V>,
+        Map: MapIteration<K, usize>,
+    {
+        self.write_snapshot_with(&JsonCodec, writer)
+    }
+
+    /// Writes a snapshot of the map to `writer`, encoding keys and values with `codec`.
+    ///
+    /// See [`ShareMap::write_snapshot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Encode`] if a key or value cannot be encoded, and
+    /// [`SnapshotError::Io`] if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    /// use share_map::snapshot::SnapshotCodec;
+    ///
+    /// /// Encodes `u32`s as 4 little-endian bytes.
+    /// struct LeCodec;
+    ///
+    /// impl SnapshotCodec<u32> for LeCodec {
+    ///     type Error = std::array::TryFromSliceError;
+    ///
+    ///     fn encode(&self, value: &u32, buf: &mut Vec<u8>) -> Result<(), Self::Error> {
+    ///         buf.extend(value.to_le_bytes());
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn decode(&self, bytes: &[u8]) -> Result<u32, Self::Error> {
+    ///         bytes.try_into().map(u32::from_le_bytes)
+    ///     }
+    /// }
+    ///
+    /// let map = ShareMap::<u32, u32>::try_from_iter([(1, 10), (2, 20)])?;
+    ///
+    /// let mut snapshot = Vec::new();
+    /// map.write_snapshot_with(&LeCodec, &mut snapshot)?;
+    ///
+    /// let restored = ShareMap::<u32, u32>::read_snapshot_with(&LeCodec, snapshot.as_slice())?;
+    /// assert_eq!(restored, map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_snapshot_with<C, W>(&self, codec: &C, writer: W) -> Result<(), SnapshotError>
+    where
+        C: SnapshotCodec<K> + SnapshotCodec<V>,
+        W: Write,
+        Map: MapIteration<K, usize>,
+    {
+        let mut writer = ChecksumWriter::new(writer);
+        let mut buf = Vec::new();
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+
+        for (key, value) in self.iter_insertion_order() {
+            buf.clear();
+            encode(codec, key, &mut buf)?;
+            writer.write_block(&buf)?;
+
+            buf.clear();
+            encode(codec, value, &mut buf)?;
+            writer.write_block(&buf)?;
+        }
+
+        let checksum = writer.checksum.finish();
+        writer.inner.write_all(&checksum.to_le_bytes())?;
+        writer.inner.flush()?;
+        Ok(())
+    }
+
+    /// Reads a [`ShareMap`] from a snapshot written by [`ShareMap::write_snapshot`].
+    ///
+    /// The snapshot must be all of the data in `reader`. The checksum is verified before any key
+    /// or value is decoded, and no map is returned unless the whole snapshot is valid. Keys and
+    /// values are decoded with [`JsonCodec`]. To use another codec, see
+    /// [`ShareMap::read_snapshot_with`].
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotError::BadMagic`] or [`SnapshotError::UnsupportedVersion`] if the data is not
+    ///   a snapshot this crate can read.
+    /// - [`SnapshotError::Truncated`] if the data ends early.
+    /// - [`SnapshotError::TrailingData`] if there is data after the snapshot.
+    /// - [`SnapshotError::ChecksumMismatch`] if the data is corrupted.
+    /// - [`SnapshotError::Decode`] if a key or value cannot be decoded.
+    /// - [`SnapshotError::DuplicateKey`] if the snapshot contains duplicate keys.
+    /// - [`SnapshotError::Io`] if reading fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use share_map::ShareMap;
+    /// use share_map::snapshot::SnapshotError;
+    ///
+    /// let map = ShareMap::<String, u32>::try_from_iter([("key1".into(), 42)])?;
+    ///
+    /// let mut snapshot = Vec::new();
+    /// map.write_snapshot(&mut snapshot)?;
+    ///
+    /// // a truncated snapshot is an error, not a partial map
+    /// let truncated = &snapshot[..snapshot.len() - 1];
+    /// let err = ShareMap::<String, u32>::read_snapshot(truncated).expect_err("should Err");
+    /// assert!(matches!(err, SnapshotError::Truncated));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_snapshot<R: Read>(reader: R) -> Result<Self, SnapshotError>
+    where
+        JsonCodec: SnapshotCodec<K> + SnapshotCodec<V>,
+        Map: FromIterator<(K, usize)> + Len,
+    {
+        Self::read_snapshot_with(&JsonCodec, reader)
+    }
+
+    /// Reads a [`ShareMap`] from a snapshot written by [`ShareMap::write_snapshot_with`],
+    /// decoding keys and values with `codec`.
+    ///
+    /// See [`ShareMap::read_snapshot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ShareMap::read_snapshot`].
+    pub fn read_snapshot_with<C, R>(codec: &C, reader: R) -> Result<Self, SnapshotError>
+    where
+        C: SnapshotCodec<K> + SnapshotCodec<V>,
+        R: Read,
+        Map: FromIterator<(K, usize)> + Len,
+    {
+        let mut reader = ChecksumReader::new(reader);
+
+        let mut magic = [0; 4];
+        reader.read_array(&mut magic)?;
+        if magic != MAGIC {
+            return Err(SnapshotError::BadMagic);
+        }
+
+        let mut version = [0; 2];
+        reader.read_array(&mut version)?;
+        match u16::from_le_bytes(version) {
+            VERSION => {}
+            version => return Err(SnapshotError::UnsupportedVersion(version)),
+        }
+
+        let mut count = [0; 8];
+        reader.read_array(&mut count)?;
+        let count = u64::from_le_bytes(count);
+
+        // the count is untrusted until the checksum is verified, so don't allocate for it all
+        let capacity = usize::try_from(count).unwrap_or(usize::MAX).min(1024);
+        let mut entries = Vec::with_capacity(capacity);
+        for _ in 0..count {
+            let key = reader.read_block()?;
+            let value = reader.read_block()?;
+            entries.push((key, value));
+        }
+
+        let actual = reader.checksum.finish();
+        let mut expected = [0; 4];
+        read_exact(&mut reader.inner, &mut expected)?;
+        let expected = u32::from_le_bytes(expected);
+
+        if expected != actual {
+            return Err(SnapshotError::ChecksumMismatch { expected, actual });
+        }
+
+        let mut trailing = Vec::new();
+        reader.inner.take(1).read_to_end(&mut trailing)?;
+        if !trailing.is_empty() {
+            return Err(SnapshotError::TrailingData);
+        }
+
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| Ok((decode(codec, &key)?, decode(codec, &value)?)))
+            .collect::<Result<Vec<_>, SnapshotError>>()?;
+
+        Self::try_from_iter(entries).map_err(SnapshotError::from)
+    }
+}
+
+fn encode<T, C: SnapshotCodec<T>>(
+    codec: &C,
+    value: &T,
+    buf: &mut Vec<u8>,
+) -> Result<(), SnapshotError> {
+    codec
+        .encode(value, buf)
+        .map_err(|err| SnapshotError::Encode(err.into()))
+}
+
+fn decode<T, C: SnapshotCodec<T>>(codec: &C, bytes: &[u8]) -> Result<T, SnapshotError> {
+    codec
+        .decode(bytes)
+        .map_err(|err| SnapshotError::Decode(err.into()))
+}
+
+/// Reads exactly `buf.len()` bytes, reporting an early end of data as truncation.
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<(), SnapshotError> {
+    reader.read_exact(buf).map_err(|err| match err.kind() {
+        ErrorKind::UnexpectedEof => SnapshotError::Truncated,
+        _ => SnapshotError::Io(err),
+    })
+}
+
+/// A writer that checksums everything written through it.
+struct ChecksumWriter<W> {
+    inner: W,
+    checksum: Crc32,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            checksum: Crc32::new(),
+        }
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        self.checksum.update(bytes);
+        Ok(self.inner.write_all(bytes)?)
+    }
+
+    /// Writes `bytes` prefixed by its length.
+    fn write_block(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        self.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        self.write_all(bytes)
+    }
+}
+
+/// A reader that checksums everything read through it.
+struct ChecksumReader<R> {
+    inner: R,
+    checksum: Crc32,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            checksum: Crc32::new(),
+        }
+    }
+
+    fn read_array(&mut self, buf: &mut [u8]) -> Result<(), SnapshotError> {
+        read_exact(&mut self.inner, buf)?;
+        self.checksum.update(buf);
+        Ok(())
+    }
+
+    /// Reads a block written by [`ChecksumWriter::write_block`].
+    fn read_block(&mut self) -> Result<Vec<u8>, SnapshotError> {
+        let mut len = [0; 8];
+        self.read_array(&mut len)?;
+        let len = u64::from_le_bytes(len);
+
+        // grow the buffer only as data actually arrives, as the length is untrusted
+        let mut bytes = Vec::new();
+        self.inner.by_ref().take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(SnapshotError::Truncated);
+        }
+
+        self.checksum.update(&bytes);
+        Ok(bytes)
+    }
+}
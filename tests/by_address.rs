@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+use share_map::{ByAddress, Handle, ShareMap};
+
+#[test]
+fn same_ref_is_equal() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+
+    let handle1 = ByAddress(map.get_handle("key1").expect("should be Some"));
+    let handle2 = ByAddress(map.get_handle("key1").expect("should be Some"));
+
+    assert_eq!(handle1, handle2);
+}
+
+#[test]
+fn different_ref_same_value_not_equal() {
+    let handle1 = ShareMap::<_, _>::try_from_iter([("key1", 42)])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+    let handle2 = ShareMap::<_, _>::try_from_iter([("key1", 42)])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+
+    assert_eq!(handle1, handle2);
+    assert_ne!(ByAddress(handle1), ByAddress(handle2));
+}
+
+#[test]
+fn same_ref_hash_is_equal() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+
+    let handle1 = ByAddress(map.get_handle("key1").expect("should be Some"));
+    let handle2 = ByAddress(map.get_handle("key1").expect("should be Some"));
+
+    let hasher = std::hash::RandomState::new();
+    assert_eq!(hasher.hash_one(&handle1), hasher.hash_one(&handle2));
+}
+
+#[test]
+fn hash_set_dedups_by_address() {
+    let map1 = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 42)]).expect("should be Ok");
+    let map2 = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+
+    let set: HashSet<_> = [
+        map1.get_handle("key1"),
+        map1.get_handle("key1"),
+        map1.get_handle("key2"),
+        map2.get_handle("key1"),
+    ]
+    .into_iter()
+    .map(|handle| ByAddress(handle.expect("should be Some")))
+    .collect();
+
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn into_inner_returns_handle() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+    let handle = map.get_handle("key1").expect("should be Some");
+
+    let inner = ByAddress::from(handle.clone()).into_inner();
+
+    assert!(Handle::ref_eq(&inner, &handle));
+}
+
+#[test]
+fn deref_matches_handle() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+    let by_address = ByAddress(map.get_handle("key1").expect("should be Some"));
+
+    assert_eq!(**by_address, 42);
+    assert_eq!(format!("{by_address:?}"), "ByAddress(42)");
+}
@@ -244,3 +244,127 @@ mod serde_tests {
         assert_eq!(handle_serialized, value_serialized);
     }
 }
+
+#[test]
+fn try_unwrap_shared_store_returns_err() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)]).expect("should be Ok");
+    let handle = map.get_handle("key1").expect("should be Some");
+
+    let handle = Handle::try_unwrap(handle).expect_err("should be Err");
+
+    assert_eq!(*handle, 42);
+}
+
+#[test]
+fn try_unwrap_unique_store_returns_value() {
+    let map =
+        ShareMap::<_, _>::try_from_iter([("key1", String::from("a")), ("key2", String::from("b"))])
+            .expect("should be Ok");
+    let handle = map.get_handle("key2").expect("should be Some");
+    drop(map);
+
+    let value = Handle::try_unwrap(handle).expect("should be Ok");
+
+    assert_eq!(value, "b");
+}
+
+#[test]
+fn into_owned_shared_store_clones_value() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", String::from("a"))]).expect("should be Ok");
+    let handle = map.get_handle("key1").expect("should be Some");
+
+    let value = Handle::into_owned(handle);
+
+    assert_eq!(value, "a");
+    assert_eq!(map["key1"], "a");
+}
+
+#[test]
+fn into_owned_unique_store_moves_value() {
+    let handle = ShareMap::<_, _>::try_from_iter([("key1", String::from("a"))])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+
+    let value = Handle::into_owned(handle);
+
+    assert_eq!(value, "a");
+}
+
+#[test]
+fn same_store_same_map_different_keys() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)]).expect("should be Ok");
+
+    let handle1 = map.get_handle("key1").expect("should be Some");
+    let handle2 = map.get_handle("key2").expect("should be Some");
+
+    assert!(Handle::same_store(&handle1, &handle2));
+}
+
+#[test]
+fn same_store_cloned_map() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42)]).expect("should be Ok");
+    let clone = map.clone();
+
+    let handle1 = map.get_handle("key1").expect("should be Some");
+    let handle2 = clone.get_handle("key1").expect("should be Some");
+
+    assert!(Handle::same_store(&handle1, &handle2));
+}
+
+#[test]
+fn same_store_different_maps() {
+    let handle1 = ShareMap::<_, _>::try_from_iter([("key1", 42)])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+    let handle2 = ShareMap::<_, _>::try_from_iter([("key1", 42)])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+
+    assert!(!Handle::same_store(&handle1, &handle2));
+}
+
+#[test]
+fn store_contains_all_values() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", 42), ("key2", 100)]).expect("should be Ok");
+    let handle = map.get_handle("key2").expect("should be Some");
+
+    assert_eq!(&**Handle::store(&handle), [42, 100]);
+}
+
+#[test]
+fn handle_is_store_and_pointer() {
+    assert_eq!(size_of::<Handle<u64>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<Handle<String>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<Handle<()>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<Option<Handle<u64>>>(), size_of::<Handle<u64>>());
+}
+
+#[test]
+fn zero_sized_handles_keep_identity() {
+    let map = ShareMap::<_, _>::try_from_iter([("key1", ()), ("key2", ())]).expect("should be Ok");
+
+    let handle1 = map.get_handle("key1").expect("should be Some");
+    let handle2 = map.get_handle("key2").expect("should be Some");
+
+    assert!(Handle::ref_ne(&handle1, &handle2));
+    assert!(Handle::ref_eq(&handle1, &handle1.clone()));
+    assert_eq!(map.key_of(&handle1), Some(&"key1"));
+    assert_eq!(map.key_of(&handle2), Some(&"key2"));
+}
+
+#[test]
+fn zero_sized_handles_different_maps_not_ref_eq() {
+    let handle1 = ShareMap::<_, _>::try_from_iter([("key1", ())])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+    let handle2 = ShareMap::<_, _>::try_from_iter([("key1", ())])
+        .expect("should be Ok")
+        .get_handle("key1")
+        .expect("should be Some");
+
+    assert!(Handle::ref_ne(&handle1, &handle2));
+}
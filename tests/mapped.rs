@@ -0,0 +1,148 @@
+#![cfg(feature = "mmap")]
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use share_map::ShareMap;
+use share_map::snapshot::{MappedShareMap, SnapshotError};
+
+static TEST_DATA: [(&str, u8); 5] = [
+    ("key1", 1),
+    ("key2", 2),
+    ("key3", 3),
+    ("key4", 4),
+    ("key5", 5),
+];
+
+/// A snapshot file that is removed when dropped.
+struct TempSnapshot(PathBuf);
+
+impl TempSnapshot {
+    fn new(name: &str, bytes: &[u8]) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("share_map_{name}_{}.shmp", std::process::id()));
+        std::fs::write(&path, bytes).expect("should be ok");
+        Self(path)
+    }
+
+    fn open<Map>(&self) -> Result<MappedShareMap<String, u8, Map>, SnapshotError>
+    where
+        Map: FromIterator<(String, usize)> + share_map::Len,
+    {
+        // SAFETY: the file is only written before it is mapped
+        unsafe { MappedShareMap::open(&self.0) }
+    }
+}
+
+impl Drop for TempSnapshot {
+    fn drop(&mut self) {
+        _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn test_snapshot() -> Vec<u8> {
+    let map: ShareMap<String, u8> =
+        ShareMap::try_from_iter(TEST_DATA.map(|(key, value)| (key.to_string(), value)))
+            .expect("should be ok");
+    let mut snapshot = Vec::new();
+    map.write_snapshot(&mut snapshot).expect("should be ok");
+    snapshot
+}
+
+#[test]
+fn mapped_get() {
+    let file = TempSnapshot::new("mapped_get", &test_snapshot());
+    let mapped = file.open::<HashMap<_, _>>().expect("should be ok");
+
+    assert_eq!(mapped.len(), TEST_DATA.len());
+    for (key, value) in TEST_DATA {
+        let mapped_value = mapped.get(key).expect("should be some");
+        assert_eq!(mapped_value.decode().expect("should be ok"), value);
+        assert!(mapped.contains_key(key));
+    }
+    assert!(mapped.get("missing").is_none());
+    assert!(!mapped.contains_key("missing"));
+}
+
+#[test]
+fn mapped_value_outlives_map() {
+    let file = TempSnapshot::new("mapped_value_outlives_map", &test_snapshot());
+    let mapped = file.open::<HashMap<_, _>>().expect("should be ok");
+
+    let value = mapped.get("key3").expect("should be some");
+    drop(mapped);
+
+    assert_eq!(value.decode().expect("should be ok"), 3);
+}
+
+#[test]
+fn mapped_iter() {
+    let file = TempSnapshot::new("mapped_iter", &test_snapshot());
+    let mapped = file.open::<BTreeMap<_, _>>().expect("should be ok");
+
+    let entries: Vec<_> = mapped
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.decode().expect("should be ok")))
+        .collect();
+
+    assert_eq!(entries, TEST_DATA);
+    assert_eq!(mapped.iter().len(), TEST_DATA.len());
+}
+
+#[test]
+fn mapped_verify() {
+    let file = TempSnapshot::new("mapped_verify", &test_snapshot());
+    let mapped = file.open::<HashMap<_, _>>().expect("should be ok");
+
+    mapped.verify().expect("should be ok");
+}
+
+#[test]
+fn mapped_corrupted_value_opens_but_fails_verify() {
+    let mut snapshot = test_snapshot();
+    // the last value, just before the checksum
+    let last_value = snapshot.len() - 5;
+    snapshot[last_value] ^= 0xFF;
+
+    let file = TempSnapshot::new("mapped_corrupted_value", &snapshot);
+    let mapped = file.open::<HashMap<_, _>>().expect("should be ok");
+    let err = mapped.verify().expect_err("should be err");
+
+    assert!(matches!(err, SnapshotError::ChecksumMismatch { .. }));
+    assert!(
+        mapped
+            .get("key5")
+            .expect("should be some")
+            .decode()
+            .is_err()
+    );
+}
+
+#[test]
+fn mapped_truncated_err() {
+    let snapshot = test_snapshot();
+
+    let file = TempSnapshot::new("mapped_truncated_err", &snapshot[..snapshot.len() - 1]);
+    let err = file.open::<HashMap<_, _>>().expect_err("should be err");
+
+    assert!(matches!(err, SnapshotError::Truncated));
+}
+
+#[test]
+fn mapped_trailing_data_err() {
+    let mut snapshot = test_snapshot();
+    snapshot.push(0);
+
+    let file = TempSnapshot::new("mapped_trailing_data_err", &snapshot);
+    let err = file.open::<HashMap<_, _>>().expect_err("should be err");
+
+    assert!(matches!(err, SnapshotError::TrailingData));
+}
+
+#[test]
+fn mapped_bad_magic_err() {
+    let file = TempSnapshot::new("mapped_bad_magic_err", b"not a snapshot");
+    let err = file.open::<HashMap<_, _>>().expect_err("should be err");
+
+    assert!(matches!(err, SnapshotError::BadMagic));
+}
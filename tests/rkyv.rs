@@ -0,0 +1,100 @@
+#![cfg(feature = "rkyv")]
+
+use std::collections::BTreeMap;
+
+use rkyv::rancor::Error;
+use share_map::ShareMap;
+
+static TEST_DATA: [(&str, u8); 5] = [
+    ("key3", 3),
+    ("key1", 1),
+    ("key5", 5),
+    ("key2", 2),
+    ("key4", 4),
+];
+
+fn test_bytes() -> rkyv::util::AlignedVec {
+    let map: ShareMap<String, u8> =
+        ShareMap::try_from_iter(TEST_DATA.map(|(key, value)| (key.to_string(), value)))
+            .expect("should be ok");
+    rkyv::to_bytes::<Error>(&map).expect("should be ok")
+}
+
+#[test]
+fn archived_get() {
+    let bytes = test_bytes();
+    let archived =
+        rkyv::access::<rkyv::Archived<ShareMap<String, u8>>, Error>(&bytes).expect("should be ok");
+
+    for (key, value) in TEST_DATA {
+        assert_eq!(archived.get(key), Some(&value));
+        assert!(archived.contains_key(key));
+    }
+    assert_eq!(archived.get("missing"), None);
+    assert!(!archived.contains_key("missing"));
+}
+
+#[test]
+fn archived_get_key_value() {
+    let bytes = test_bytes();
+    let archived =
+        rkyv::access::<rkyv::Archived<ShareMap<String, u8>>, Error>(&bytes).expect("should be ok");
+
+    let (key, value) = archived.get_key_value("key2").expect("should be some");
+    assert_eq!(key.as_str(), "key2");
+    assert_eq!(*value, 2);
+}
+
+#[test]
+fn archived_iter_is_key_ordered() {
+    let bytes = test_bytes();
+    let archived =
+        rkyv::access::<rkyv::Archived<ShareMap<String, u8>>, Error>(&bytes).expect("should be ok");
+
+    let entries: Vec<_> = archived
+        .iter()
+        .map(|(key, value)| (key.as_str(), *value))
+        .collect();
+    let expected: Vec<_> = BTreeMap::from(TEST_DATA).into_iter().collect();
+
+    assert_eq!(entries, expected);
+    assert_eq!(archived.len(), TEST_DATA.len());
+    assert!(!archived.is_empty());
+}
+
+#[test]
+fn archived_integer_keys() {
+    let map =
+        ShareMap::<i32, u8>::try_from_iter([(300, 3), (-20, 1), (7, 2)]).expect("should be ok");
+    let bytes = rkyv::to_bytes::<Error>(&map).expect("should be ok");
+    let archived =
+        rkyv::access::<rkyv::Archived<ShareMap<i32, u8>>, Error>(&bytes).expect("should be ok");
+
+    let keys: Vec<_> = archived
+        .into_iter()
+        .map(|(key, _)| key.to_native())
+        .collect();
+    assert_eq!(keys, [-20, 7, 300]);
+    assert_eq!(archived.get(&(-20).into()), Some(&1));
+    assert_eq!(archived.get(&300.into()), Some(&3));
+}
+
+#[test]
+fn archived_empty() {
+    let map = ShareMap::<String, u8>::try_from_iter([]).expect("should be ok");
+    let bytes = rkyv::to_bytes::<Error>(&map).expect("should be ok");
+    let archived =
+        rkyv::access::<rkyv::Archived<ShareMap<String, u8>>, Error>(&bytes).expect("should be ok");
+
+    assert!(archived.is_empty());
+    assert_eq!(archived.get("key1"), None);
+}
+
+#[test]
+fn archived_invalid_bytes_err() {
+    let bytes = test_bytes();
+    let truncated = &bytes[..bytes.len() / 2];
+
+    rkyv::access::<rkyv::Archived<ShareMap<String, u8>>, Error>(truncated)
+        .expect_err("should be err");
+}
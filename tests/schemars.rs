@@ -0,0 +1,132 @@
+#![cfg(feature = "schemars")]
+
+use schemars::{JsonSchema, schema_for};
+use serde_json::{Value, json};
+use share_map::{Handle, ShareMap, SliceHandle, ensure_unique};
+
+fn property(schema: &schemars::Schema, name: &str) -> Value {
+    schema
+        .get("properties")
+        .and_then(|properties| properties.get(name))
+        .cloned()
+        .expect("should be some")
+}
+
+#[test]
+fn share_map_schema_is_object() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        map: ShareMap<String, bool>,
+    }
+
+    let schema = schema_for!(Container);
+
+    assert_eq!(
+        property(&schema, "map"),
+        json!({ "type": "object", "additionalProperties": { "type": "boolean" } })
+    );
+}
+
+#[test]
+fn share_map_schema_with_integer_keys_is_object() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        map: ShareMap<u32, bool>,
+    }
+
+    let schema = schema_for!(Container);
+
+    assert_eq!(
+        property(&schema, "map"),
+        json!({ "type": "object", "additionalProperties": { "type": "boolean" } })
+    );
+}
+
+#[test]
+fn share_map_schema_with_tuple_keys_is_array_of_pairs() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        map: ShareMap<(u8, u8), bool>,
+    }
+
+    let schema = schema_for!(Container);
+    let map = property(&schema, "map");
+
+    assert_eq!(map.get("type"), Some(&json!("array")));
+    assert_eq!(
+        map.pointer("/items/prefixItems/0/prefixItems/0/type"),
+        Some(&json!("integer"))
+    );
+    assert_eq!(
+        map.pointer("/items/prefixItems/1"),
+        Some(&json!({ "type": "boolean" }))
+    );
+}
+
+#[test]
+fn handle_schema_is_value_schema() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        handle: Handle<String>,
+        slice: SliceHandle<bool>,
+    }
+
+    let schema = schema_for!(Container);
+
+    assert_eq!(property(&schema, "handle"), json!({ "type": "string" }));
+    assert_eq!(
+        property(&schema, "slice"),
+        json!({ "type": "array", "items": { "type": "boolean" } })
+    );
+}
+
+#[test]
+fn ensure_unique_schema_has_unique_keys() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        #[schemars(schema_with = "ensure_unique::json_schema::<bool>")]
+        map: ShareMap<String, bool>,
+    }
+
+    let schema = schema_for!(Container);
+
+    assert_eq!(
+        property(&schema, "map"),
+        json!({
+            "type": "object",
+            "additionalProperties": { "type": "boolean" },
+            "uniqueKeys": true,
+        })
+    );
+}
+
+#[test]
+fn as_pairs_schema_is_array_of_pairs() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Container {
+        #[schemars(schema_with = "share_map::serde::as_pairs::json_schema::<bool, String>")]
+        map: ShareMap<bool, String>,
+    }
+
+    let schema = schema_for!(Container);
+
+    assert_eq!(
+        property(&schema, "map"),
+        json!({
+            "type": "array",
+            "items": {
+                "type": "array",
+                "prefixItems": [{ "type": "boolean" }, { "type": "string" }],
+                "minItems": 2,
+                "maxItems": 2,
+            },
+            "uniqueKeys": true,
+        })
+    );
+}
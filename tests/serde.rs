@@ -1,6 +1,13 @@
 #![cfg(feature = "serde")]
 
-use share_map::{ShareMap, ensure_unqiue};
+use std::collections::{BTreeMap, HashMap};
+
+use frozen_collections::FzOrderedMap;
+use serde::de::DeserializeSeed;
+
+use share_map::handle_ref::{HandleRef, HandleSeed};
+use share_map::serde::{DuplicatePolicy, KeyedBy, ShareMapSeed};
+use share_map::{Handle, KeySpec, ShareMap, ensure_unique};
 
 static TEST_DATA: [(&str, u8); 5] = [
     ("key1", 1),
@@ -22,12 +29,12 @@ fn serde_roundtrip() {
 
 #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 struct TestContainer {
-    #[serde(with = "ensure_unqiue")]
+    #[serde(with = "ensure_unique")]
     map: ShareMap<String, u8>,
 }
 
 #[test]
-fn deserialize_ensure_unqiue_duplicate_keys_errors() {
+fn deserialize_ensure_unique_duplicate_keys_errors() {
     let data = r#"{"map": {"key1": 1, "key2": 2, "key1": 3}}"#;
 
     let err = serde_json::from_str::<TestContainer>(data).expect_err("should Err");
@@ -48,7 +55,7 @@ fn serde_ensured_unique_roundtrip() {
 }
 
 #[test]
-fn deserialize_ensure_unqiue_wrong_type_uses_expecting() {
+fn deserialize_ensure_unique_wrong_type_uses_expecting() {
     // Test with a string instead of a map
     let data = r#"{"map": "not a map"}"#;
     let err = serde_json::from_str::<TestContainer>(data).expect_err("should Err");
@@ -66,9 +73,364 @@ fn deserialize_ensure_unqiue_wrong_type_uses_expecting() {
 }
 
 #[test]
-fn deserialize_ensure_unqiue_malformed_entry_errors() {
+fn deserialize_ensure_unique_malformed_entry_errors() {
     // Map expects String keys, but we provide a number as a key
     let data = r#"{"map": {123: "value"}}"#;
     let err = serde_json::from_str::<TestContainer>(data).expect_err("should Err");
     assert!(err.is_syntax());
 }
+
+#[test]
+fn serialize_view_matches_filtered_map() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view = map.view(|key, _| *key == "key2");
+
+    let serialized = serde_json::to_string(&view).expect("should be ok");
+
+    assert_eq!(serialized, r#"{"key2":2}"#);
+}
+
+#[test]
+fn handle_ref_roundtrip_shares_store() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let handles = [
+        map.get_handle("key1").expect("should be some"),
+        map.get_handle("key3").expect("should be some"),
+    ];
+
+    let refs = handles
+        .each_ref()
+        .map(|handle| HandleRef::new(&map, handle));
+    let serialized = serde_json::to_string(&refs).expect("should be ok");
+    assert_eq!(serialized, r#"["key1","key3"]"#);
+
+    let seed = HandleSeed::new(&map);
+    for (key, handle) in ["key1", "key3"].into_iter().zip(&handles) {
+        let json = format!(r#""{key}""#);
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let resolved = seed.deserialize(&mut deserializer).expect("should be ok");
+        assert!(Handle::ref_eq(&resolved, handle));
+    }
+}
+
+#[test]
+fn handle_ref_unknown_key_errors() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""key6""#);
+    let err = HandleSeed::new(&map)
+        .deserialize(&mut deserializer)
+        .expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[test]
+fn handle_ref_wrong_type_errors() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let mut deserializer = serde_json::Deserializer::from_str("1");
+    let err = HandleSeed::new(&map)
+        .deserialize(&mut deserializer)
+        .expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[test]
+fn handle_ref_foreign_handle_errors() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let other = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let foreign = other.get_handle("key1").expect("should be some");
+
+    serde_json::to_string(&HandleRef::new(&map, &foreign)).expect_err("should Err");
+}
+
+#[test]
+fn handle_ref_across_threads() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let map = &map;
+    let seed = HandleSeed::new(map);
+
+    std::thread::scope(|scope| {
+        for (key, _) in TEST_DATA {
+            scope.spawn(move || {
+                let handle = map.get_handle(key).expect("should be some");
+                let serialized =
+                    serde_json::to_string(&HandleRef::new(map, &handle)).expect("should be ok");
+
+                let mut deserializer = serde_json::Deserializer::from_str(&serialized);
+                let resolved = seed.deserialize(&mut deserializer).expect("should be ok");
+                assert!(Handle::ref_eq(&resolved, &handle));
+            });
+        }
+    });
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct PairsContainer {
+    #[serde(with = "share_map::serde::as_pairs")]
+    map: ShareMap<(u8, u8), String>,
+}
+
+#[test]
+fn as_pairs_roundtrip() {
+    let data = [((1, 2), "a".to_string()), ((0, 0), "b".to_string())];
+    let container = PairsContainer {
+        map: ShareMap::try_from_iter(data).expect("should be ok"),
+    };
+
+    let serialized = serde_json::to_string(&container).expect("should be ok");
+    assert_eq!(serialized, r#"{"map":[[[1,2],"a"],[[0,0],"b"]]}"#);
+
+    let deserialized: PairsContainer = serde_json::from_str(&serialized).expect("should be ok");
+    assert_eq!(container, deserialized);
+}
+
+#[test]
+fn as_pairs_duplicate_keys_errors() {
+    let data = r#"{"map":[[[1,2],"a"],[[1,2],"b"]]}"#;
+
+    let err = serde_json::from_str::<PairsContainer>(data).expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[test]
+fn ensure_unique_is_reexported_from_serde_module() {
+    #[derive(serde::Deserialize)]
+    struct Container {
+        #[serde(with = "share_map::serde::ensure_unique")]
+        map: ShareMap<String, u8>,
+    }
+
+    let container: Container = serde_json::from_str(r#"{"map":{"key1":1}}"#).expect("should be ok");
+    assert_eq!(container.map.get("key1"), Some(&1));
+}
+
+#[test]
+#[allow(deprecated)]
+fn ensure_unqiue_alias_still_deserializes() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Container {
+        #[serde(with = "share_map::ensure_unqiue")]
+        map: ShareMap<String, u8>,
+    }
+
+    let container: Container = serde_json::from_str(r#"{"map":{"key1":1}}"#).expect("should be ok");
+    assert_eq!(container.map.get("key1"), Some(&1));
+
+    serde_json::from_str::<Container>(r#"{"map":{"key1":1,"key1":2}}"#).expect_err("should Err");
+}
+
+#[test]
+fn deserialize_duplicates_keep_last_value() {
+    let data = r#"{"key1": 1, "key2": 2, "key1": 3}"#;
+
+    let map: ShareMap<String, u8> = serde_json::from_str(data).expect("should be ok");
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("key1"), Some(&3));
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+struct OrdOnlyKey(String);
+
+#[test]
+fn deserialize_ordered_backend_does_not_require_hash() {
+    let data = r#"{"key2": 2, "key1": 1}"#;
+
+    let map: ShareMap<OrdOnlyKey, u8, BTreeMap<_, _>> =
+        serde_json::from_str(data).expect("should be ok");
+
+    assert_eq!(map.get(&OrdOnlyKey("key1".to_string())), Some(&1));
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), [2, 1]);
+}
+
+#[test]
+fn deserialize_frozen_backend() {
+    let data = r#"{"key2": 2, "key1": 1, "key2": 3}"#;
+
+    let map: ShareMap<String, u8, FzOrderedMap<_, _>> =
+        serde_json::from_str(data).expect("should be ok");
+
+    assert_eq!(map.get(&"key2".to_string()), Some(&3));
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), [3, 1]);
+}
+
+#[test]
+fn seed_duplicate_policies() {
+    let data = r#"{"key1": 1, "key2": 2, "key1": 3}"#;
+    let deserialize = |policy| {
+        let mut deserializer = serde_json::Deserializer::from_str(data);
+        ShareMapSeed::<String, u8, HashMap<_, _>>::new(policy).deserialize(&mut deserializer)
+    };
+
+    let first = deserialize(DuplicatePolicy::KeepFirst).expect("should be ok");
+    assert_eq!(first.get("key1"), Some(&1));
+    assert_eq!(first.values().copied().collect::<Vec<_>>(), [1, 2]);
+
+    let last = deserialize(DuplicatePolicy::KeepLast).expect("should be ok");
+    assert_eq!(last.get("key1"), Some(&3));
+    assert_eq!(last.values().copied().collect::<Vec<_>>(), [3, 2]);
+
+    let err = deserialize(DuplicatePolicy::Reject).expect_err("should Err");
+    assert!(err.is_data());
+}
+
+#[test]
+fn seed_keep_first_still_checks_dropped_values() {
+    let data = r#"{"key1": 1, "key1": "one"}"#;
+
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+    let err = ShareMapSeed::<String, u8, HashMap<_, _>>::new(DuplicatePolicy::KeepFirst)
+        .deserialize(&mut deserializer)
+        .expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct SortedContainer {
+    #[serde(with = "share_map::serde::sorted")]
+    map: ShareMap<String, u8>,
+}
+
+#[test]
+fn sorted_serialization_is_deterministic() {
+    let data = TEST_DATA.map(|(key, value)| (key.to_string(), value));
+    let reversed: Vec<_> = data.iter().rev().cloned().collect();
+
+    let forward = SortedContainer {
+        map: ShareMap::try_from_iter(data).expect("should be ok"),
+    };
+    let backward = SortedContainer {
+        map: ShareMap::try_from_iter(reversed).expect("should be ok"),
+    };
+
+    let forward = serde_json::to_string(&forward).expect("should be ok");
+    let backward = serde_json::to_string(&backward).expect("should be ok");
+
+    assert_eq!(forward, backward);
+    assert_eq!(
+        forward,
+        r#"{"map":{"key1":1,"key2":2,"key3":3,"key4":4,"key5":5}}"#
+    );
+}
+
+#[test]
+fn sorted_deserialize_duplicate_keys_errors() {
+    let data = r#"{"map": {"key1": 1, "key1": 2}}"#;
+
+    let err = serde_json::from_str::<SortedContainer>(data).expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[test]
+fn serialize_sorted_matches_btreemap() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let btree_map = BTreeMap::from(TEST_DATA);
+
+    // compare the output text, as `serde_json::Value` maps are always sorted
+    let mut sorted = Vec::new();
+    map.serialize_sorted(&mut serde_json::Serializer::new(&mut sorted))
+        .expect("should be ok");
+
+    assert_eq!(
+        String::from_utf8(sorted).expect("should be ok"),
+        serde_json::to_string(&btree_map).expect("should be ok")
+    );
+}
+
+struct RequiredKeys;
+
+impl KeySpec for RequiredKeys {
+    const REQUIRED: &'static [&'static str] = &["key1", "key2"];
+    const DENIED: &'static [&'static str] = &["key3"];
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct KeysContainer {
+    #[serde(deserialize_with = "share_map::serde::keys::deserialize::<RequiredKeys, _, _, _, _>")]
+    map: ShareMap<String, u8>,
+}
+
+#[test]
+fn keys_deserialize_satisfied() {
+    let data = r#"{"map": {"key1": 1, "key2": 2, "key4": 4}}"#;
+
+    let container: KeysContainer = serde_json::from_str(data).expect("should be ok");
+
+    assert_eq!(container.map.len(), 3);
+}
+
+#[test]
+fn keys_deserialize_lists_missing_and_unexpected() {
+    let data = r#"{"map": {"key2": 2, "key3": 3}}"#;
+
+    let err = serde_json::from_str::<KeysContainer>(data).expect_err("should Err");
+
+    assert!(err.is_data());
+    assert!(
+        err.to_string()
+            .contains(r#"missing keys ["key1"], unexpected keys ["key3"]"#)
+    );
+}
+
+#[test]
+fn keys_deserialize_duplicate_keys_errors() {
+    let data = r#"{"map": {"key1": 1, "key2": 2, "key1": 3}}"#;
+
+    let err = serde_json::from_str::<KeysContainer>(data).expect_err("should Err");
+
+    assert!(err.is_data());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Item {
+    id: u8,
+    name: String,
+}
+
+impl KeyedBy<u8> for Item {
+    fn key(&self) -> u8 {
+        self.id
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct KeyedContainer {
+    #[serde(with = "share_map::serde::keyed_by_field")]
+    map: ShareMap<u8, Item, BTreeMap<u8, usize>>,
+}
+
+#[test]
+fn keyed_by_field_roundtrip() {
+    let data = r#"{"map":[{"id":2,"name":"b"},{"id":1,"name":"a"}]}"#;
+
+    let container: KeyedContainer = serde_json::from_str(data).expect("should be ok");
+
+    assert_eq!(
+        container.map.get(&1).map(|item| item.name.as_str()),
+        Some("a")
+    );
+    assert_eq!(
+        container.map.get(&2).map(|item| item.name.as_str()),
+        Some("b")
+    );
+    assert_eq!(
+        serde_json::to_string(&container).expect("should be ok"),
+        data
+    );
+}
+
+#[test]
+fn keyed_by_field_duplicate_keys_name_key() {
+    let data = r#"{"map":[{"id":1,"name":"a"},{"id":2,"name":"b"},{"id":1,"name":"c"}]}"#;
+
+    let err = serde_json::from_str::<KeyedContainer>(data).expect_err("should Err");
+
+    assert!(err.is_data());
+    assert!(err.to_string().contains("duplicate key 1"));
+}
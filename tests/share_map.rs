@@ -2,8 +2,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use collect_failable::TryCollectEx;
+use frozen_collections::FzOrderedMap;
 
-use share_map::ShareMap;
+use share_map::{Handle, KeySpec, ShareMap};
 
 static TEST_DATA: [(&str, u8); 5] = [
     ("key1", 1),
@@ -260,7 +261,531 @@ fn map_into_iter_borrowed() {
     let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(TEST_DATA).expect("should be ok");
 
     let borrowed_vec: Vec<_> = TEST_DATA.iter().map(|(k, v)| (k, v)).collect();
-    let frozen_vec: Vec<_> = map.into_iter().collect();
+    let frozen_vec: Vec<_> = (&map).into_iter().collect();
 
     assert_eq!(borrowed_vec, frozen_vec);
 }
+
+#[test]
+fn try_into_entries_unique_store_returns_entries() {
+    let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let entries = map.try_into_entries().expect("should be ok");
+
+    assert_eq!(entries, TEST_DATA);
+}
+
+#[test]
+fn try_into_entries_shared_store_returns_map() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let clone = map.clone();
+
+    let map = map.try_into_entries().expect_err("should be err");
+
+    assert_eq!(map, clone);
+}
+
+#[test]
+fn owns_handle_from_same_map() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let clone = map.clone();
+
+    let handle = map.get_handle("key1").expect("should be some");
+
+    assert!(map.owns(&handle));
+    assert!(clone.owns(&handle));
+}
+
+#[test]
+fn owns_handle_from_different_map_is_false() {
+    let map1 = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let map2 = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let handle = map1.get_handle("key1").expect("should be some");
+
+    assert!(!map2.owns(&handle));
+}
+
+#[test]
+fn key_of_returns_key() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    for (key, _) in TEST_DATA {
+        let handle = map.get_handle(key).expect("should be some");
+        assert_eq!(map.key_of(&handle), Some(&key));
+    }
+}
+
+#[test]
+fn key_of_handle_from_different_map_returns_none() {
+    let map1 = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let map2 = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let handle = map1.get_handle("key1").expect("should be some");
+
+    assert_eq!(map2.key_of(&handle), None);
+}
+
+#[test]
+fn handles_match_values() {
+    let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let handles: Vec<_> = map.handles().collect();
+
+    assert_eq!(handles.len(), TEST_DATA.len());
+    for ((key, handle), (data_key, data_value)) in handles.iter().zip(TEST_DATA) {
+        assert_eq!(**key, data_key);
+        assert_eq!(**handle, data_value);
+        assert!(map.owns(handle));
+    }
+}
+
+#[test]
+fn map_into_iter_owned_yields_keys_and_handles() {
+    let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(TEST_DATA).expect("should be ok");
+    let clone = map.clone();
+
+    let entries: Vec<_> = map.into_iter().collect();
+
+    assert_eq!(entries.len(), TEST_DATA.len());
+    for ((key, handle), (data_key, data_value)) in entries.iter().zip(TEST_DATA) {
+        assert_eq!(*key, data_key);
+        assert_eq!(**handle, data_value);
+        assert!(clone.owns(handle));
+    }
+}
+
+#[test]
+fn into_hashmap_shared_store_clones_values() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let handle = map.get_handle("key1").expect("should be some");
+
+    let hash_map: HashMap<_, _> = map.into();
+
+    assert_eq!(hash_map, HashMap::from(TEST_DATA));
+    assert_eq!(*handle, 1);
+}
+
+#[test]
+fn ordered_range() {
+    let map: ShareMap<_, _, BTreeMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let pairs: Vec<_> = map.range("key2".."key4").collect();
+    assert_eq!(pairs, [(&"key2", &2), (&"key3", &3)]);
+
+    let pairs: Vec<_> = map.range("key4"..).rev().collect();
+    assert_eq!(pairs, [(&"key5", &5), (&"key4", &4)]);
+}
+
+#[test]
+fn ordered_first_last() {
+    let map: ShareMap<_, _, BTreeMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    assert_eq!(map.first_key_value(), Some((&"key1", &1)));
+    assert_eq!(map.last_key_value(), Some((&"key5", &5)));
+
+    let empty: ShareMap<&str, u8, BTreeMap<_, _>> = ShareMap::default();
+    assert_eq!(empty.first_key_value(), None);
+    assert_eq!(empty.last_key_value(), None);
+}
+
+#[test]
+fn ordered_navigation() {
+    let data = [(10, "a"), (20, "b"), (30, "c")];
+    let map: ShareMap<_, _, BTreeMap<_, _>> =
+        data.into_iter().try_collect_ex().expect("should be ok");
+
+    assert_eq!(map.floor(&25), Some((&20, &"b")));
+    assert_eq!(map.floor(&20), Some((&20, &"b")));
+    assert_eq!(map.floor(&5), None);
+
+    assert_eq!(map.ceiling(&25), Some((&30, &"c")));
+    assert_eq!(map.ceiling(&20), Some((&20, &"b")));
+    assert_eq!(map.ceiling(&35), None);
+
+    assert_eq!(map.predecessor(&20), Some((&10, &"a")));
+    assert_eq!(map.predecessor(&10), None);
+
+    assert_eq!(map.successor(&20), Some((&30, &"c")));
+    assert_eq!(map.successor(&30), None);
+}
+
+#[test]
+fn ordered_prefix() {
+    let data = [
+        ("app.name", 1),
+        ("app.port", 2),
+        ("apple", 3),
+        ("db.host", 4),
+    ];
+    let map: ShareMap<_, _, BTreeMap<_, _>> =
+        data.into_iter().try_collect_ex().expect("should be ok");
+
+    let pairs: Vec<_> = map.prefix("app.").collect();
+    assert_eq!(pairs, [(&"app.name", &1), (&"app.port", &2)]);
+
+    assert_eq!(map.prefix("zzz").count(), 0);
+}
+
+#[test]
+fn get_many() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    assert_eq!(
+        map.get_many(["key1", "key5", "key6"]),
+        [Some(&1), Some(&5), None]
+    );
+    assert_eq!(map.get_many::<str, 0>([]), []);
+}
+
+#[test]
+fn get_many_iter() {
+    let map: ShareMap<_, _, BTreeMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let values: Vec<_> = map.get_many_iter(["key6", "key2"]).collect();
+    assert_eq!(values, [None, Some(&2)]);
+}
+
+#[test]
+fn get_many_frozen_map() {
+    let map: ShareMap<_, _, FzOrderedMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    assert_eq!(
+        map.get_many([&"key5", &"key6", &"key1"]),
+        [Some(&5), None, Some(&1)]
+    );
+    let batch = map.get_many_handles([&"key2", &"key7"]);
+    assert_eq!(batch.values(), [Some(&2), None]);
+}
+
+#[test]
+fn get_many_handles() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let batch = map.get_many_handles(["key1", "key6", "key2"]);
+    let handle = map.get_handle("key2").expect("should be some");
+
+    drop(map);
+    assert_eq!(batch.values(), [Some(&1), None, Some(&2)]);
+    assert_eq!(batch.get(0), Some(&1));
+    assert_eq!(batch.get(1), None);
+    assert_eq!(batch.get(3), None);
+    assert!(Handle::ref_eq(
+        &batch.handle(2).expect("should be some"),
+        &handle
+    ));
+
+    let [first, missing, second] = batch.into_handles();
+    assert_eq!(first.as_deref(), Some(&1));
+    assert!(missing.is_none());
+    assert_eq!(second.as_deref(), Some(&2));
+}
+
+#[test]
+fn get_key_value() {
+    let keys: Vec<Arc<str>> = TEST_DATA.iter().map(|(key, _)| Arc::from(*key)).collect();
+    let data = keys
+        .iter()
+        .cloned()
+        .zip(TEST_DATA.iter().map(|(_, value)| *value));
+    let map: ShareMap<_, _, BTreeMap<_, _>> = data.try_collect_ex().expect("should be ok");
+
+    let (key, value) = map.get_key_value("key2").expect("should be some");
+    assert!(Arc::ptr_eq(key, &keys[1]), "should be the stored key");
+    assert_eq!(*value, 2);
+
+    assert_eq!(map.get_key_value("key6"), None);
+}
+
+#[test]
+fn get_key_handle() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let (key, handle) = map.get_key_handle("key3").expect("should be some");
+    assert_eq!((*key, *handle), ("key3", 3));
+
+    assert!(map.get_key_handle("key6").is_none());
+}
+
+#[test]
+fn values_are_in_insertion_order() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn from_iter_duplicates_keep_source_order() {
+    let map: ShareMap<_, _> = DUPLICATE_DATA.into_iter().collect();
+
+    // HashMap keeps the last value seen, which keeps its position in the source
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, [2, 3, 4, 5, 6]);
+    assert_eq!(map["key1"], 6);
+}
+
+#[test]
+fn iter_insertion_order() {
+    let data = [("key3", 3), ("key1", 1), ("key2", 2)];
+    let map: ShareMap<_, _, BTreeMap<_, _>> =
+        data.into_iter().try_collect_ex().expect("should be ok");
+
+    let pairs: Vec<_> = map
+        .iter_insertion_order()
+        .map(|(key, value)| (*key, *value))
+        .collect();
+    assert_eq!(pairs, data);
+
+    let mut iter = map.iter_insertion_order();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some((&"key2", &2)));
+}
+
+#[test]
+fn map_values_keeps_keys() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let doubled = map.map_values(|_, value| u16::from(*value) * 2);
+
+    assert_eq!(doubled.len(), map.len());
+    for (key, value) in &map {
+        assert_eq!(doubled.get(key), Some(&(u16::from(*value) * 2)));
+    }
+}
+
+#[test]
+fn map_values_preserves_insertion_order() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let mut seen = Vec::new();
+    let keys = map.map_values(|key, _| seen.push(*key));
+
+    assert_eq!(keys.len(), TEST_DATA.len());
+    assert_eq!(seen, TEST_DATA.map(|(key, _)| key));
+}
+
+#[test]
+fn try_map_values_reports_failing_key() {
+    let map: ShareMap<_, _, BTreeMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let ok = map
+        .try_map_values(|_, value| u8::try_from(u16::from(*value) * 10))
+        .expect("should be ok");
+    assert_eq!(ok.get("key5"), Some(&50));
+
+    let (key, err) = map
+        .try_map_values(|_, value| {
+            if *value == 3 {
+                Err("three")
+            } else {
+                Ok(*value)
+            }
+        })
+        .expect_err("should fail");
+    assert_eq!((key, err), ("key3", "three"));
+}
+
+#[test]
+fn union_with_merges_shared_keys() {
+    let left = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2)]).expect("should be ok");
+    let right = ShareMap::<_, _>::try_from_iter([("b", 20), ("c", 30)]).expect("should be ok");
+
+    let union = left.union_with(&right, |_, ours, theirs| ours + theirs);
+
+    let values: Vec<_> = union.values().copied().collect();
+    assert_eq!(values, [1, 22, 30]);
+    assert_eq!(union.get("b"), Some(&22));
+}
+
+#[test]
+fn intersection_and_difference_partition_keys() {
+    let left: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+    let right = ShareMap::<_, _>::try_from_iter([("key2", ()), ("key4", ()), ("key6", ())])
+        .expect("should be ok");
+
+    let both = left.intersection(&right);
+    let only_left = left.difference(&right);
+
+    assert_eq!(both.values().copied().collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(only_left.values().copied().collect::<Vec<_>>(), [1, 3, 5]);
+}
+
+#[test]
+fn symmetric_difference_excludes_shared_keys() {
+    let left = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2)]).expect("should be ok");
+    let right = ShareMap::<_, _>::try_from_iter([("b", 20), ("c", 30)]).expect("should be ok");
+
+    let either = left.symmetric_difference(&right);
+
+    let pairs: Vec<_> = either.iter_insertion_order().collect();
+    assert_eq!(pairs, [(&"a", &1), (&"c", &30)]);
+}
+
+#[test]
+fn set_operations_accept_other_map_types() {
+    let left = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2)]).expect("should be ok");
+    let right = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter([("b", 20), ("c", 30)])
+        .expect("should be ok");
+
+    let union = left.union_with(&right, |_, _, theirs| *theirs);
+    let either = left.symmetric_difference(&right);
+    let both = left.intersection(&right);
+    let only_left = left.difference(&right);
+
+    assert_eq!(union.values().copied().collect::<Vec<_>>(), [1, 20, 30]);
+    assert_eq!(either.values().copied().collect::<Vec<_>>(), [1, 30]);
+    assert_eq!(both.values().copied().collect::<Vec<_>>(), [2]);
+    assert_eq!(only_left.values().copied().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn set_operations_share_store_when_keeping_whole_map() {
+    let left = ShareMap::<_, _>::try_from_iter([("a", 1), ("b", 2)]).expect("should be ok");
+    let right =
+        ShareMap::<_, _>::try_from_iter([("a", 10), ("b", 20), ("c", 30)]).expect("should be ok");
+    let empty = ShareMap::<&str, i32>::default();
+
+    let handle = left.get_handle("a").expect("should be Some");
+    let both = left.intersection(&right);
+    let only_left = left.difference(&empty);
+    let union = empty.union_with(&left, |_, _, _| unreachable!());
+    let either = left.symmetric_difference(&empty);
+
+    for map in [both, only_left, union, either] {
+        assert_eq!(map.len(), 2);
+        let shared = map.get_handle("a").expect("should be Some");
+        assert!(Handle::ref_eq(&handle, &shared));
+    }
+
+    let partial = right.intersection(&left);
+    let shared = right.get_handle("a").expect("should be Some");
+    let copied = partial.get_handle("a").expect("should be Some");
+    assert!(!Handle::ref_eq(&shared, &copied));
+}
+
+#[derive(Debug, PartialEq)]
+struct PanicOnClone(u8);
+
+impl Clone for PanicOnClone {
+    fn clone(&self) -> Self {
+        panic!("should not be cloned")
+    }
+}
+
+#[test]
+fn filter_unique_store_moves_values() {
+    let data = TEST_DATA.map(|(key, value)| (key, PanicOnClone(value)));
+    let map: ShareMap<_, _> = data.into_iter().try_collect_ex().expect("should be ok");
+
+    let odd = map.filter(|_, value| value.0 % 2 == 1);
+
+    assert_eq!(odd.len(), 3);
+    assert_eq!(odd.get("key3"), Some(&PanicOnClone(3)));
+    assert_eq!(odd.get("key2"), None);
+}
+
+#[test]
+fn filter_shared_store_clones_values() {
+    let map: ShareMap<_, _> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+    let handle = map.get_handle("key2").expect("should be some");
+
+    let filtered = map.filter(|key, _| *key != "key1");
+
+    assert_eq!(filtered.values().copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+    assert_eq!(*handle, 2);
+}
+
+#[test]
+fn filter_shared_store_clones_only_retained_values() {
+    let data = TEST_DATA.map(|(key, value)| (key, PanicOnClone(value)));
+    let map: ShareMap<_, _> = data.into_iter().try_collect_ex().expect("should be ok");
+    let handle = map.get_handle("key2").expect("should be some");
+
+    let none = map.filter(|_, _| false);
+
+    assert!(none.is_empty());
+    assert_eq!(*handle, PanicOnClone(2));
+}
+
+#[test]
+fn partition_splits_entries() {
+    let map: ShareMap<_, _, BTreeMap<_, _>> = TEST_DATA
+        .into_iter()
+        .try_collect_ex()
+        .expect("should be ok");
+
+    let (small, large) = map.partition(|_, value| *value < 3);
+
+    assert_eq!(small.keys().copied().collect::<Vec<_>>(), ["key1", "key2"]);
+    assert_eq!(
+        large.keys().copied().collect::<Vec<_>>(),
+        ["key3", "key4", "key5"]
+    );
+}
+
+struct TestKeys;
+
+impl KeySpec for TestKeys {
+    const REQUIRED: &'static [&'static str] = &["key1", "key2"];
+    const ALLOWED: Option<&'static [&'static str]> = Some(&["key3", "key4", "key5"]);
+    const DENIED: &'static [&'static str] = &["key5"];
+}
+
+#[test]
+fn require_keys_satisfied() {
+    let map =
+        ShareMap::<_, _>::try_from_iter(TEST_DATA[..4].iter().copied()).expect("should be ok");
+
+    map.require_keys::<TestKeys>().expect("should be ok");
+}
+
+#[test]
+fn require_keys_reports_missing_and_unexpected() {
+    let map = ShareMap::<_, _>::try_from_iter([("key2", 2), ("key5", 5), ("key0", 0)])
+        .expect("should be ok");
+
+    let err = map.require_keys::<TestKeys>().expect_err("should Err");
+
+    assert_eq!(err.missing(), ["key1"]);
+    assert_eq!(err.unexpected(), ["key0", "key5"]);
+}
@@ -0,0 +1,151 @@
+use share_map::{Handle, ShareMap, SliceHandle};
+
+static TEST_DATA: [(&str, u8); 5] = [
+    ("key1", 1),
+    ("key2", 2),
+    ("key3", 3),
+    ("key4", 4),
+    ("key5", 5),
+];
+
+#[test]
+fn get_slice_handle_returns_inclusive_range() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let slice = map
+        .get_slice_handle("key2", "key4")
+        .expect("should be Some");
+
+    assert_eq!(*slice, [2, 3, 4]);
+}
+
+#[test]
+fn get_slice_handle_single_key_returns_single_value() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    let slice = map
+        .get_slice_handle("key3", "key3")
+        .expect("should be Some");
+
+    assert_eq!(*slice, [3]);
+}
+
+#[test]
+fn get_slice_handle_reversed_keys_returns_none() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    assert!(map.get_slice_handle("key4", "key2").is_none());
+}
+
+#[test]
+fn get_slice_handle_invalid_key_returns_none() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+
+    assert!(map.get_slice_handle("key1", "key6").is_none());
+    assert!(map.get_slice_handle("key6", "key1").is_none());
+}
+
+#[test]
+fn slice_handle_outlives_map() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key1", "key5")
+        .expect("should be Some");
+
+    drop(map);
+
+    assert_eq!(*slice, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn get_returns_ref_eq_handle() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key2", "key4")
+        .expect("should be Some");
+
+    let handle = SliceHandle::get(&slice, 1).expect("should be Some");
+    let map_handle = map.get_handle("key3").expect("should be Some");
+
+    assert!(Handle::ref_eq(&handle, &map_handle));
+    assert!(SliceHandle::get(&slice, 3).is_none());
+}
+
+#[test]
+fn split_at_divides_slice() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key1", "key5")
+        .expect("should be Some");
+
+    let (left, right) = SliceHandle::split_at(slice, 2);
+
+    assert_eq!(*left, [1, 2]);
+    assert_eq!(*right, [3, 4, 5]);
+}
+
+#[test]
+fn split_at_end_returns_empty_right() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key1", "key2")
+        .expect("should be Some");
+
+    let (left, right) = SliceHandle::split_at(slice, 2);
+
+    assert_eq!(*left, [1, 2]);
+    assert!(right.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "mid > len")]
+fn split_at_out_of_bounds_panics() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key1", "key2")
+        .expect("should be Some");
+
+    _ = SliceHandle::split_at(slice, 3);
+}
+
+#[test]
+fn into_iter_yields_handles() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key2", "key4")
+        .expect("should be Some");
+
+    let mut iter = slice.into_iter();
+    assert_eq!(iter.len(), 3);
+
+    let last = iter.next_back().expect("should be Some");
+    assert!(Handle::ref_eq(
+        &last,
+        &map.get_handle("key4").expect("should be Some")
+    ));
+
+    let values: Vec<_> = iter.map(|handle| *handle).collect();
+    assert_eq!(values, [2, 3]);
+}
+
+#[test]
+fn borrowed_into_iter_yields_values() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key2", "key4")
+        .expect("should be Some");
+
+    let values: Vec<_> = (&slice).into_iter().collect();
+
+    assert_eq!(values, [&2, &3, &4]);
+}
+
+#[test]
+fn debug_matches_slice() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let slice = map
+        .get_slice_handle("key2", "key4")
+        .expect("should be Some");
+
+    assert_eq!(format!("{slice:?}"), format!("{:?}", [2, 3, 4]));
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "snapshot")]
+
+use std::collections::BTreeMap;
+
+use share_map::ShareMap;
+use share_map::snapshot::{SnapshotCodec, SnapshotError};
+
+static TEST_DATA: [(&str, u8); 5] = [
+    ("key1", 1),
+    ("key2", 2),
+    ("key3", 3),
+    ("key4", 4),
+    ("key5", 5),
+];
+
+fn test_map() -> ShareMap<String, u8> {
+    ShareMap::try_from_iter(TEST_DATA.map(|(key, value)| (key.to_string(), value)))
+        .expect("should be ok")
+}
+
+fn test_snapshot() -> Vec<u8> {
+    let mut snapshot = Vec::new();
+    test_map()
+        .write_snapshot(&mut snapshot)
+        .expect("should be ok");
+    snapshot
+}
+
+#[test]
+fn snapshot_roundtrip() {
+    let map = test_map();
+
+    let restored =
+        ShareMap::<String, u8>::read_snapshot(test_snapshot().as_slice()).expect("should be ok");
+
+    assert_eq!(map, restored);
+}
+
+#[test]
+fn snapshot_preserves_insertion_order_across_backends() {
+    let restored =
+        ShareMap::<String, u8, BTreeMap<_, _>>::read_snapshot(test_snapshot().as_slice())
+            .expect("should be ok");
+
+    assert_eq!(
+        restored.values().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn snapshot_is_deterministic() {
+    assert_eq!(test_snapshot(), test_snapshot());
+}
+
+#[test]
+fn snapshot_bad_magic_errors() {
+    let mut snapshot = test_snapshot();
+    snapshot[0] = b'X';
+
+    let err = ShareMap::<String, u8>::read_snapshot(snapshot.as_slice()).expect_err("should Err");
+
+    assert!(matches!(err, SnapshotError::BadMagic));
+}
+
+#[test]
+fn snapshot_unsupported_version_errors() {
+    let mut snapshot = test_snapshot();
+    snapshot[4..6].copy_from_slice(&2u16.to_le_bytes());
+
+    let err = ShareMap::<String, u8>::read_snapshot(snapshot.as_slice()).expect_err("should Err");
+
+    assert!(matches!(err, SnapshotError::UnsupportedVersion(2)));
+}
+
+#[test]
+fn snapshot_every_truncation_errors() {
+    let snapshot = test_snapshot();
+
+    for len in 0..snapshot.len() {
+        let err = ShareMap::<String, u8>::read_snapshot(&snapshot[..len]).expect_err("should Err");
+        assert!(
+            matches!(err, SnapshotError::Truncated | SnapshotError::BadMagic),
+            "len {len}: {err}"
+        );
+    }
+}
+
+#[test]
+fn snapshot_every_corrupted_byte_errors() {
+    let snapshot = test_snapshot();
+
+    // skip the magic and version, which have their own errors
+    for index in 6..snapshot.len() {
+        let mut corrupted = snapshot.clone();
+        corrupted[index] ^= 0x01;
+
+        ShareMap::<String, u8>::read_snapshot(corrupted.as_slice()).expect_err("should Err");
+    }
+}
+
+#[test]
+fn snapshot_corrupted_entry_is_checksum_mismatch() {
+    let mut snapshot = test_snapshot();
+    // the first key's bytes start after the header and the key length
+    snapshot[4 + 2 + 8 + 8 + 1] ^= 0x01;
+
+    let err = ShareMap::<String, u8>::read_snapshot(snapshot.as_slice()).expect_err("should Err");
+
+    assert!(matches!(err, SnapshotError::ChecksumMismatch { .. }));
+}
+
+#[test]
+fn snapshot_trailing_data_errors() {
+    let mut snapshot = test_snapshot();
+    snapshot.push(0);
+
+    let err = ShareMap::<String, u8>::read_snapshot(snapshot.as_slice()).expect_err("should Err");
+
+    assert!(matches!(err, SnapshotError::TrailingData));
+}
+
+/// Encodes a `u8` as a single byte, masked with `mask`.
+struct ByteCodec {
+    mask: u8,
+}
+
+impl SnapshotCodec<u8> for ByteCodec {
+    type Error = std::io::Error;
+
+    fn encode(&self, value: &u8, buf: &mut Vec<u8>) -> Result<(), Self::Error> {
+        buf.push(value & self.mask);
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<u8, Self::Error> {
+        match bytes {
+            [byte] => Ok(*byte),
+            _ => Err(std::io::Error::other("expected one byte")),
+        }
+    }
+}
+
+#[test]
+fn snapshot_custom_codec_roundtrip() {
+    let codec = ByteCodec { mask: u8::MAX };
+    let map = ShareMap::<u8, u8>::try_from_iter([(1, 10), (2, 20)]).expect("should be ok");
+
+    let mut snapshot = Vec::new();
+    map.write_snapshot_with(&codec, &mut snapshot)
+        .expect("should be ok");
+    let read =
+        ShareMap::<u8, u8>::read_snapshot_with(&codec, snapshot.as_slice()).expect("should be ok");
+
+    assert_eq!(read.values().copied().collect::<Vec<_>>(), [10, 20]);
+    assert_eq!(read.get(&2), Some(&20));
+}
+
+#[test]
+fn snapshot_duplicate_keys_errors() {
+    // the codec drops the low bit, so distinct keys decode as duplicates
+    let codec = ByteCodec { mask: !1 };
+    let map = ShareMap::<u8, u8>::try_from_iter([(2, 1), (3, 2)]).expect("should be ok");
+
+    let mut snapshot = Vec::new();
+    map.write_snapshot_with(&codec, &mut snapshot)
+        .expect("should be ok");
+    let err = ShareMap::<u8, u8>::read_snapshot_with(&codec, snapshot.as_slice())
+        .expect_err("should Err");
+
+    assert!(matches!(err, SnapshotError::DuplicateKey(_)));
+}
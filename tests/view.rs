@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use share_map::{Handle, ShareMap, ShareMapView};
+
+static TEST_DATA: [(&str, u8); 5] = [
+    ("key1", 1),
+    ("key2", 2),
+    ("key3", 3),
+    ("key4", 4),
+    ("key5", 5),
+];
+
+#[test]
+fn view_contains_only_matching_entries() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view = map.view(|_, value| value % 2 == 0);
+
+    assert_eq!(view.len(), 2);
+    assert!(!view.is_empty());
+    assert_eq!(view.get("key2"), Some(&2));
+    assert_eq!(view.get("key4"), Some(&4));
+    assert_eq!(view.get("key1"), None);
+    assert!(!view.contains_key("key3"));
+}
+
+#[test]
+fn view_handles_are_ref_eq_to_parent_handles() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view = map.view(|key, _| *key == "key3");
+
+    let from_view = view.get_handle("key3").expect("should be some");
+    let from_map = map.get_handle("key3").expect("should be some");
+
+    assert!(Handle::ref_eq(&from_view, &from_map));
+    assert!(map.owns(&from_view));
+}
+
+#[test]
+fn view_outlives_parent() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view: ShareMapView<_, _> = map.view(|_, _| true);
+    drop(map);
+
+    assert_eq!(view.get("key5"), Some(&5));
+}
+
+#[test]
+fn view_iter_matches_filtered_map() {
+    let map = ShareMap::<_, _, BTreeMap<_, _>>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view = map.view(|_, value| *value > 3);
+
+    let pairs: Vec<_> = view.iter().collect();
+    assert_eq!(pairs, [(&"key4", &4), (&"key5", &5)]);
+    assert_eq!(format!("{view:?}"), r#"{"key4": 4, "key5": 5}"#);
+}
+
+#[test]
+fn empty_view() {
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA).expect("should be ok");
+    let view = map.view(|_, _| false);
+
+    assert!(view.is_empty());
+    assert_eq!(view.iter().count(), 0);
+}
+
+#[test]
+fn view_clone_does_not_require_value_clone() {
+    #[derive(Debug, PartialEq)]
+    struct NotClone(u8);
+
+    let map = ShareMap::<_, _>::try_from_iter(TEST_DATA.map(|(key, value)| (key, NotClone(value))))
+        .expect("should be ok");
+    let view = map.view(|_, value| value.0 > 3);
+    let cloned = view.clone();
+
+    let from_view = view.get_handle("key4").expect("should be some");
+    let from_clone = cloned.get_handle("key4").expect("should be some");
+    assert!(Handle::ref_eq(&from_view, &from_clone));
+    assert_eq!(cloned.get("key5"), Some(&NotClone(5)));
+}